@@ -0,0 +1,385 @@
+//! Derive macro for the [parsers] crate.
+//!
+//! Use it through the `derive` feature of [parsers].
+//!
+//! [parsers]: https://docs.rs/parsers
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input,
+    parse_quote,
+    spanned::Spanned,
+    Attribute,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    GenericArgument,
+    Ident,
+    Lifetime,
+    LitStr,
+    PathArguments,
+    Type,
+};
+
+/// Derives the `Parse` trait.
+///
+/// A struct is parsed as the sequence of its fields, an enum tries its variants
+/// in order and returns the first match. Every field is parsed by the `Parse`
+/// implementation of its type. If no variant matches, the error of the last one is returned.
+/// An enum without variants fails with `Failed::Nothing`.
+///
+/// The `#[parse(...)]` attribute accepts:
+///
+/// * `literal = "..."`: the literal to match before the field, or before all fields
+///   of the struct or the variant. A unit variant must have it.
+/// * `suffix = "..."`: the literal to match after the field, the struct or the variant.
+/// * `skip_white`: skip whitespace before every literal and field. On a struct,
+///   an enum or a variant it applies to everything inside.
+/// * `sep = "..."`: parse a `Vec` field as any number of elements separated by the literal.
+///
+/// The macro implements `Parse` rather than `Rule`. A rule is a value applied to the input,
+/// while the derived type is the value the input is parsed into, so it has no `self` to match with.
+/// Use `compound::parse::<T>()` to get the rule of the type and combine it with other rules.
+///
+/// Every type is parsed from the start of its input, so a left recursive type
+/// would recurse until the stack overflows. A struct or a variant whose first field is the type
+/// itself, `Self`, or a `Box`, `Option` or `Vec` of it, without a `literal` before the field,
+/// is rejected at compile time. Left recursion through other types isn't detected.
+///
+/// ```compile_fail
+/// use parsers_derive::Parse;
+///
+/// #[derive(Parse)]
+/// enum Expr {
+///     Sub(Box<Expr>, #[parse(literal = "-")] i64),
+///     Num(i64),
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use parsers::{prelude::*, compound::parse};
+/// use parsers_derive::Parse;
+///
+/// #[derive(Debug, PartialEq, Parse)]
+/// #[parse(skip_white)]
+/// enum Value {
+///     #[parse(literal = "null")]
+///     Null,
+///     Bool(bool),
+///     Int(i64),
+///     #[parse(literal = "[", suffix = "]")]
+///     List(#[parse(sep = ",")] Vec<Value>),
+/// }
+///
+/// assert_eq!(parse::<Value>().rule("[1, [null], true]"), Match(Value::List(vec![
+///     Value::Int(1),
+///     Value::List(vec![Value::Null]),
+///     Value::Bool(true),
+/// ]), ""));
+/// ```
+#[proc_macro_derive(Parse, attributes(parse))]
+pub fn derive_parse(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct Attrs {
+    literal: Option<LitStr>,
+    suffix: Option<LitStr>,
+    sep: Option<LitStr>,
+    skip_white: bool,
+}
+
+impl Attrs {
+    fn parse(attrs: &[Attribute], inherit: bool) -> syn::Result<Self> {
+        let mut res = Attrs {
+            skip_white: inherit,
+            ..Attrs::default()
+        };
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("parse")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("literal") {
+                    res.literal = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("suffix") {
+                    res.suffix = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("sep") {
+                    res.sep = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip_white") {
+                    res.skip_white = true;
+                } else {
+                    return Err(meta.error("expected `literal`, `suffix`, `sep` or `skip_white`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(res)
+    }
+
+    fn no_sep(self) -> syn::Result<Self> {
+        match &self.sep {
+            Some(sep) => Err(Error::new(sep.span(), "`sep` is allowed on fields only")),
+            None => Ok(self),
+        }
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = Attrs::parse(&input.attrs, false)?.no_sep()?;
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+    let lt = match generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let lt = Lifetime::new("'__i", name.span());
+            generics.params.insert(0, parse_quote!(#lt));
+            lt
+        }
+    };
+
+    let type_params: Vec<_> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for param in type_params {
+        where_clause.predicates.push(parse_quote!(#param: ::parsers::Parse<#lt>));
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let seq = sequence(&data.fields, &attrs, quote!(Self), name, &lt)?;
+            quote! {
+                #[allow(unused_mut)]
+                let mut rest = input;
+                #seq
+            }
+        }
+        Data::Enum(data) => {
+            let mut variants = Vec::new();
+            let mut calls = Vec::new();
+
+            for (i, variant) in data.variants.iter().enumerate() {
+                let attrs = Attrs::parse(&variant.attrs, attrs.skip_white)?.no_sep()?;
+
+                if variant.fields.is_empty() && attrs.literal.is_none() {
+                    return Err(Error::new(variant.span(), "a unit variant must have a `literal`"));
+                }
+
+                let ident = &variant.ident;
+                let seq = sequence(&variant.fields, &attrs, quote!(Self::#ident), name, &lt)?;
+                let var = format_ident!("variant_{}", i);
+                variants.push(quote! {
+                    #[allow(unused_mut)]
+                    let #var = |mut rest: &#lt str| -> ::parsers::Ruled<&#lt str, Self, ::parsers::Failed<'static>> {
+                        #seq
+                    };
+                });
+                calls.push(match calls.is_empty() {
+                    true => quote!(#var(input)),
+                    false => quote!(.or_else(|_| #var(input))),
+                });
+            }
+
+            if calls.is_empty() {
+                quote!(::parsers::Ruled::Expected(::parsers::Failed::Nothing))
+            } else {
+                quote! {
+                    #(#variants)*
+                    #(#calls)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(data.union_token.span, "unions are not supported"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::parsers::Parse<#lt> for #name #ty_generics #where_clause {
+            fn parse(input: &#lt str) -> ::parsers::Ruled<&#lt str, Self, ::parsers::Failed<'static>> {
+                #body
+            }
+        }
+    })
+}
+
+/// Generates the code skipping whitespace of the variable.
+fn skip_white(skip: bool, var: &TokenStream) -> TokenStream {
+    if skip {
+        quote! {
+            if let ::parsers::Ruled::Match(_, r) = ::parsers::Rule::rule(&::parsers::basic::Whites, #var) {
+                #var = r;
+            }
+        }
+    } else {
+        quote!()
+    }
+}
+
+/// Generates the code matching the literal or returning the error.
+fn literal(lit: &Option<LitStr>, skip: bool) -> TokenStream {
+    match lit {
+        Some(lit) => {
+            let skip = skip_white(skip, &quote!(rest));
+            quote! {
+                #skip
+                rest = {
+                    static LITERAL: &str = #lit;
+                    match ::parsers::Rule::rule(LITERAL, rest) {
+                        ::parsers::Ruled::Match(_, r) => r,
+                        ::parsers::Ruled::Expected(e) => return ::parsers::Ruled::Expected(e),
+                    }
+                };
+            }
+        }
+        None => quote!(),
+    }
+}
+
+/// Returns the element type of the `Vec` type.
+fn vec_elem(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if segment.ident == "Vec" && args.args.len() == 1 => {
+            match args.args.first()? {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the type is the named type, `Self`, or a `Box`, `Option` or `Vec` of it.
+fn refers_to(ty: &Type, name: &Ident) -> bool {
+    let segment = match ty {
+        Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => segment,
+            None => return false,
+        },
+        _ => return false,
+    };
+
+    if segment.ident == *name || segment.ident == "Self" {
+        return true;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if ["Box", "Option", "Vec"].iter().any(|w| segment.ident == w) => {
+            match args.args.first() {
+                Some(GenericArgument::Type(ty)) => refers_to(ty, name),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Generates the code parsing the fields into the constructor.
+fn sequence(fields: &Fields, attrs: &Attrs, ctor: TokenStream, name: &Ident, lt: &Lifetime) -> syn::Result<TokenStream> {
+    let prefix = literal(&attrs.literal, attrs.skip_white);
+    let suffix = literal(&attrs.suffix, attrs.skip_white);
+    let mut parsed = Vec::new();
+    let mut vars = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let field_attrs = Attrs::parse(&field.attrs, attrs.skip_white)?;
+        let var = format_ident!("field_{}", i);
+        let ty = &field.ty;
+        let skip = skip_white(field_attrs.skip_white, &quote!(rest));
+        let field_prefix = literal(&field_attrs.literal, field_attrs.skip_white);
+        let field_suffix = literal(&field_attrs.suffix, field_attrs.skip_white);
+
+        if i == 0 && attrs.literal.is_none() && field_attrs.literal.is_none() && refers_to(ty, name) {
+            return Err(Error::new(ty.span(), "the first field is left recursive, add a `literal` before it"));
+        }
+
+        let value = match &field_attrs.sep {
+            Some(sep) => {
+                let elem = vec_elem(ty)
+                    .ok_or_else(|| Error::new(ty.span(), "`sep` requires a `Vec` field"))?;
+                let skip_next = skip_white(field_attrs.skip_white, &quote!(next));
+
+                quote! {{
+                    static SEP: &str = #sep;
+                    let mut items = ::std::vec::Vec::new();
+                    #skip
+
+                    if let ::parsers::Ruled::Match(m, r) = <#elem as ::parsers::Parse<#lt>>::parse(rest) {
+                        rest = r;
+                        items.push(m);
+
+                        loop {
+                            let mut next = rest;
+                            #skip_next
+                            next = match ::parsers::Rule::rule(SEP, next) {
+                                ::parsers::Ruled::Match(_, r) => r,
+                                ::parsers::Ruled::Expected(_) => break,
+                            };
+                            #skip_next
+
+                            match <#elem as ::parsers::Parse<#lt>>::parse(next) {
+                                ::parsers::Ruled::Match(m, r) => {
+                                    rest = r;
+                                    items.push(m);
+                                }
+                                ::parsers::Ruled::Expected(_) => break,
+                            }
+                        }
+                    }
+
+                    items
+                }}
+            }
+            None => quote! {{
+                #skip
+                match <#ty as ::parsers::Parse<#lt>>::parse(rest) {
+                    ::parsers::Ruled::Match(m, r) => {
+                        rest = r;
+                        m
+                    }
+                    ::parsers::Ruled::Expected(e) => return ::parsers::Ruled::Expected(e),
+                }
+            }},
+        };
+
+        parsed.push(quote! {
+            #field_prefix
+            let #var = #value;
+            #field_suffix
+        });
+        vars.push(var);
+    }
+
+    let value = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote!(#ctor { #(#names: #vars),* })
+        }
+        Fields::Unnamed(_) => quote!(#ctor(#(#vars),*)),
+        Fields::Unit => ctor,
+    };
+
+    Ok(quote! {
+        #prefix
+        #(#parsed)*
+        #suffix
+        ::parsers::Ruled::Match(#value, rest)
+    })
+}
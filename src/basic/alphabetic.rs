@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+/// Match an alphabetic char.
+///
+/// The char has the Unicode `Alphabetic` property.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::alphabetic};
+/// assert_eq!(alphabetic().rule("Шq"), Match("Ш", "q"));
+/// assert!(!alphabetic().test("1"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Alphabetic;
+
+/// Constructor of [`Alphabetic`]
+///
+/// [`Alphabetic`]: ./struct.Alphabetic.html
+pub fn alphabetic() -> Alphabetic { Alphabetic }
+
+impl<'r, 'i> Rule<'r, &'i str> for Alphabetic {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if c.is_alphabetic() => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::Alphabetic),
+        }
+    }
+}
+
+impl_ops!(Alphabetic);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alphabetic() {
+        assert_eq!(super::alphabetic().rule("Шq"), Match("Ш", "q"));
+        assert_eq!(super::alphabetic().rule("字"), Match("字", ""));
+        assert_eq!(super::alphabetic().rule("1"), Expected(Failed::Alphabetic));
+        assert_eq!(super::alphabetic().rule(""), Expected(Failed::Alphabetic));
+    }
+}
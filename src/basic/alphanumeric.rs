@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+/// Match an alphabetic or numeric char.
+///
+/// The char has the Unicode `Alphabetic` property or the `Numeric` general category.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::alphanumeric};
+/// assert!(alphanumeric().test("ä"));
+/// assert!(alphanumeric().test("٣"));
+/// assert!(!alphanumeric().test("_"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Alphanumeric;
+
+/// Constructor of [`Alphanumeric`]
+///
+/// [`Alphanumeric`]: ./struct.Alphanumeric.html
+pub fn alphanumeric() -> Alphanumeric { Alphanumeric }
+
+impl<'r, 'i> Rule<'r, &'i str> for Alphanumeric {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if c.is_alphanumeric() => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::Alphanumeric),
+        }
+    }
+}
+
+impl_ops!(Alphanumeric);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alphanumeric() {
+        assert!(super::alphanumeric().test("ä"));
+        assert!(super::alphanumeric().test("٣"));
+        assert_eq!(super::alphanumeric().rule("_"), Expected(Failed::Alphanumeric));
+    }
+}
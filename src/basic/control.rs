@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// Match a control char.
+///
+/// The char has the Unicode general category `Cc`.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::control};
+/// assert!(control().test("\u{7}"));
+/// assert!(!control().test("a"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Control;
+
+/// Constructor of [`Control`]
+///
+/// [`Control`]: ./struct.Control.html
+pub fn control() -> Control { Control }
+
+impl<'r, 'i> Rule<'r, &'i str> for Control {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if c.is_control() => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::Control),
+        }
+    }
+}
+
+impl_ops!(Control);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control() {
+        assert!(super::control().test("\u{7}"));
+        assert!(super::control().test("\n"));
+        assert_eq!(super::control().rule("a"), Expected(Failed::Control));
+    }
+}
@@ -0,0 +1,509 @@
+use crate::prelude::*;
+
+/// An interface of floating-point types that [`Float`] can produce.
+///
+/// [`Float`]: ./struct.Float.html
+pub trait FloatNumber: Copy + std::str::FromStr {
+    /// The number of explicitly stored mantissa bits.
+    const MANTISSA_BITS: u32;
+
+    /// The number of exponent bits.
+    const EXPONENT_BITS: u32;
+
+    /// Creates a value from the raw bits in the low part of `u64`.
+    fn from_raw(bits: u64) -> Self;
+
+    /// Returns the negated value.
+    fn neg(self) -> Self;
+}
+
+impl FloatNumber for f32 {
+    const MANTISSA_BITS: u32 = 23;
+    const EXPONENT_BITS: u32 = 8;
+
+    fn from_raw(bits: u64) -> Self { f32::from_bits(bits as u32) }
+
+    fn neg(self) -> Self { -self }
+}
+
+impl FloatNumber for f64 {
+    const MANTISSA_BITS: u32 = 52;
+    const EXPONENT_BITS: u32 = 11;
+
+    fn from_raw(bits: u64) -> Self { f64::from_bits(bits) }
+
+    fn neg(self) -> Self { -self }
+}
+
+/// Floating-point number parser.
+///
+/// Returns the correctly rounded value and the matched slice of the input.
+/// Decimal numbers are converted by the standard library straight from the input,
+/// hexadecimal ones (`0x1.8p3`) are rounded by the rule itself.
+/// Fails with [`Failed::Float`] if there is no number.
+///
+/// By default, the rule accepts a sign, a fraction with optional digits on either
+/// side of the point, an exponent, `inf`, `infinity`, `nan` and hex floats.
+/// Use [`json`] and [`rust`] to switch to the stricter syntax.
+///
+/// [`Failed::Float`]: ../enum.Failed.html#variant.Float
+/// [`json`]: ./struct.Float.html#method.json
+/// [`rust`]: ./struct.Float.html#method.rust
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::float};
+/// assert_eq!(float::<f64>().rule("-1.5e3;"), Match((-1500.0, "-1.5e3"), ";"));
+/// assert_eq!(float::<f64>().rule("0x1.8p1"), Match((3.0, "0x1.8p1"), ""));
+/// assert_eq!(float::<f64>().json().rule(".5"), Expected(Failed::Float));
+/// assert_eq!(float::<f32>().rust().rule("1_000.0"), Match((1000.0, "1_000.0"), ""));
+/// ```
+#[derive(Debug)]
+pub struct Float<T> {
+    plus: bool,
+    leading_dot: bool,
+    trailing_dot: bool,
+    method_dot: bool,
+    leading_zeros: bool,
+    separators: bool,
+    special: bool,
+    hex: bool,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T> Float<T> {
+    /// Switches to the strict JSON syntax.
+    ///
+    /// Only `-` sign is allowed, the integer part is required and has no leading zeros,
+    /// the fraction must have digits. No special values and hex floats.
+    pub fn json(self) -> Self {
+        Float {
+            plus: false,
+            leading_dot: false,
+            trailing_dot: false,
+            method_dot: false,
+            leading_zeros: false,
+            separators: false,
+            special: false,
+            hex: false,
+            ..self
+        }
+    }
+
+    /// Switches to the syntax of Rust float literals.
+    ///
+    /// Only `-` sign is allowed, the integer part is required,
+    /// digits may be separated by `_`. The point without a fraction isn't
+    /// consumed before an identifier, `_` or another point, as in `1.max(2)` or `1..2`.
+    /// No special values and hex floats.
+    pub fn rust(self) -> Self {
+        Float {
+            plus: false,
+            leading_dot: false,
+            trailing_dot: true,
+            method_dot: true,
+            leading_zeros: true,
+            separators: true,
+            special: false,
+            hex: false,
+            ..self
+        }
+    }
+
+    /// Allows `_` separators between digits.
+    ///
+    /// Separators are stripped before the conversion in a stack buffer,
+    /// only literals with separators longer than 64 bytes are copied to a `String`.
+    pub fn separators(self) -> Self { Float { separators: true, ..self } }
+}
+
+impl<T> Clone for Float<T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for Float<T> {}
+
+/// [`Float`] constructor. See [`Float`] for details.
+///
+/// [`Float`]: ./struct.Float.html
+pub fn float<T>() -> Float<T>
+    where
+        T: FloatNumber,
+{
+    Float {
+        plus: true,
+        leading_dot: true,
+        trailing_dot: true,
+        method_dot: false,
+        leading_zeros: true,
+        separators: false,
+        special: true,
+        hex: true,
+        phantom: std::marker::PhantomData,
+    }
+}
+
+/// Returns the end of digits starting at `pos` and `true` if there were separators.
+fn digits(bytes: &[u8], pos: usize, separators: bool, radix: u32) -> (usize, bool) {
+    let mut end = pos;
+    let mut sep = false;
+
+    while let Some(&b) = bytes.get(end) {
+        if (b as char).is_digit(radix) {
+            end += 1;
+        } else if separators && b == b'_' && end > pos {
+            sep = true;
+            end += 1;
+        } else {
+            break;
+        }
+    }
+
+    (end, sep)
+}
+
+/// Builds raw bits of a float of `m * 2^exp`, rounded to nearest, ties to even.
+/// The `sticky` flag means there are nonzero bits below `m`.
+fn round_bits(mut m: u64, exp: i64, sticky: bool, mantissa_bits: u32, exponent_bits: u32) -> u64 {
+    if m == 0 {
+        return 0;
+    }
+
+    let bias = (1i64 << (exponent_bits - 1)) - 1;
+    let infinity = ((1u64 << exponent_bits) - 1) << mantissa_bits;
+
+    let lz = m.leading_zeros();
+    m <<= lz;
+    let e = exp + 63 - lz as i64;
+
+    if e > bias {
+        return infinity;
+    }
+
+    let min_exp = 1 - bias;
+    let shift = if e >= min_exp {
+        63 - mantissa_bits as i64
+    } else {
+        63 - mantissa_bits as i64 + (min_exp - e)
+    };
+
+    if shift > 64 {
+        // Less than a half of the smallest subnormal
+        return 0;
+    }
+
+    let (mut kept, rem, half) = if shift == 64 {
+        (0, m, 1 << 63)
+    } else {
+        (m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1))
+    };
+
+    let up = rem > half || rem == half && (sticky || kept & 1 == 1);
+
+    if up {
+        kept += 1;
+    }
+
+    if e < min_exp {
+        // Subnormal, the rounding may carry into the exponent field
+        return kept;
+    }
+
+    let mut e = e;
+    if kept >> (mantissa_bits + 1) != 0 {
+        kept >>= 1;
+        e += 1;
+    }
+
+    if e > bias {
+        return infinity;
+    }
+
+    (((e + bias) as u64) << mantissa_bits) | (kept & ((1 << mantissa_bits) - 1))
+}
+
+impl<T> Float<T>
+    where
+        T: FloatNumber,
+{
+    /// Parses a hex float after the `0x` prefix. Returns the value and the end.
+    fn hex(&self, bytes: &[u8], mut pos: usize) -> Option<(T, usize)> {
+        let mut m: u64 = 0;
+        let mut exp: i64 = 0;
+        let mut sticky = false;
+        let mut any = false;
+
+        let mut push = |d: u32, frac: bool| {
+            if m >> 60 == 0 {
+                m = m * 16 + d as u64;
+                if frac {
+                    exp -= 4;
+                }
+            } else {
+                sticky |= d != 0;
+                if !frac {
+                    exp += 4;
+                }
+            }
+        };
+
+        let (end, _) = digits(bytes, pos, self.separators, 16);
+        for &b in &bytes[pos..end] {
+            if let Some(d) = (b as char).to_digit(16) {
+                push(d, false);
+                any = true;
+            }
+        }
+        pos = end;
+
+        if bytes.get(pos) == Some(&b'.') {
+            let (end, _) = digits(bytes, pos + 1, self.separators, 16);
+            for &b in &bytes[pos + 1..end] {
+                if let Some(d) = (b as char).to_digit(16) {
+                    push(d, true);
+                    any = true;
+                }
+            }
+            pos = end;
+        }
+
+        if !any {
+            return None;
+        }
+
+        match bytes.get(pos) {
+            Some(b'p') | Some(b'P') => pos += 1,
+            _ => return None,
+        }
+
+        let neg = match bytes.get(pos) {
+            Some(b'-') => { pos += 1; true }
+            Some(b'+') => { pos += 1; false }
+            _ => false,
+        };
+
+        let (end, _) = digits(bytes, pos, self.separators, 10);
+        if end == pos {
+            return None;
+        }
+
+        let p = bytes[pos..end].iter()
+            .filter_map(|&b| (b as char).to_digit(10))
+            .fold(0i64, |p, d| (p * 10 + d as i64).min(1 << 20));
+
+        exp += if neg { -p } else { p };
+
+        let bits = round_bits(m, exp, sticky, T::MANTISSA_BITS, T::EXPONENT_BITS);
+        Some((T::from_raw(bits), end))
+    }
+
+    /// Returns the end of a decimal number and `true` if there were separators.
+    fn decimal(&self, bytes: &[u8], pos: usize) -> Option<(usize, bool)> {
+        let digit = |pos: usize| bytes.get(pos).is_some_and(u8::is_ascii_digit);
+
+        let (mut end, mut sep) = if digit(pos) {
+            if !self.leading_zeros && bytes[pos] == b'0' {
+                (pos + 1, false)
+            } else {
+                digits(bytes, pos, self.separators, 10)
+            }
+        } else {
+            (pos, false)
+        };
+
+        let int = end > pos;
+
+        if bytes.get(end) == Some(&b'.') {
+            if digit(end + 1) {
+                let (e, s) = digits(bytes, end + 1, self.separators, 10);
+                end = e;
+                sep |= s;
+            } else if int && self.trailing_dot {
+                let next = std::str::from_utf8(&bytes[end + 1..]).ok()
+                    .and_then(|s| s.chars().next());
+
+                match next {
+                    Some(c) if self.method_dot && (c == '.' || c == '_' || c.is_alphabetic()) => {}
+                    _ => end += 1,
+                }
+            }
+        }
+
+        if !int && (!self.leading_dot || end == pos) {
+            return None;
+        }
+
+        if let Some(b'e') | Some(b'E') = bytes.get(end) {
+            let mut e = end + 1;
+            if let Some(b'-') | Some(b'+') = bytes.get(e) {
+                e += 1;
+            }
+
+            let (e_end, s) = digits(bytes, e, self.separators, 10);
+            if bytes[e..e_end].iter().any(u8::is_ascii_digit) {
+                end = e_end;
+                sep |= s;
+            }
+        }
+
+        Some((end, sep))
+    }
+}
+
+impl<'r, 'i, T> Rule<'r, &'i str> for Float<T>
+    where
+        T: FloatNumber,
+{
+    type Mat = (T, &'i str);
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        let bytes = input.as_bytes();
+
+        let (neg, pos) = match bytes.first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') if self.plus => (false, 1),
+            _ => (false, 0),
+        };
+
+        if self.special {
+            let rest = &input[pos..];
+
+            for name in &["infinity", "inf", "nan"] {
+                if rest.get(..name.len()).is_some_and(|s| s.eq_ignore_ascii_case(name)) {
+                    let end = pos + name.len();
+                    return match input[..end].parse() {
+                        Ok(f) => Match((f, &input[..end]), &input[end..]),
+                        Err(_) => Expected(Failed::Float),
+                    };
+                }
+            }
+        }
+
+        if self.hex {
+            if let Some(b"0x") | Some(b"0X") = bytes.get(pos..pos + 2) {
+                if let Some((f, end)) = self.hex(bytes, pos + 2) {
+                    let f = if neg { f.neg() } else { f };
+                    return Match((f, &input[..end]), &input[end..]);
+                }
+            }
+        }
+
+        let (end, sep) = match self.decimal(bytes, pos) {
+            Some(d) => d,
+            None => return Expected(Failed::Float),
+        };
+
+        let (text, rest) = input.split_at(end);
+
+        let parsed = if sep {
+            let mut buf = [0; 64];
+            let len = text.bytes().filter(|&b| b != b'_').count();
+
+            if len <= buf.len() {
+                for (d, s) in buf.iter_mut().zip(text.bytes().filter(|&b| b != b'_')) {
+                    *d = s;
+                }
+
+                std::str::from_utf8(&buf[..len]).ok().and_then(|s| s.parse().ok())
+            } else {
+                // Only literals longer than the buffer allocate
+                text.replace('_', "").parse().ok()
+            }
+        } else {
+            text.parse().ok()
+        };
+
+        match parsed {
+            Some(f) => Match((f, text), rest),
+            None => Expected(Failed::Float),
+        }
+    }
+}
+
+impl_ops!(Float<N>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value<T>(r: Ruled<&str, (T, &str), Failed>) -> Option<T> { r.mat().map(|(f, _)| f) }
+
+    #[test]
+    fn float() {
+        let r = super::float::<f64>();
+        assert_eq!(r.rule("1.5;"), Match((1.5, "1.5"), ";"));
+        assert_eq!(r.rule("-2"), Match((-2.0, "-2"), ""));
+        assert_eq!(r.rule("+.5e-1"), Match((0.05, "+.5e-1"), ""));
+        assert_eq!(r.rule("5.x"), Match((5.0, "5."), "x"));
+        assert_eq!(r.rule("1e"), Match((1.0, "1"), "e"));
+        assert_eq!(r.rule("1e+"), Match((1.0, "1"), "e+"));
+        assert_eq!(r.rule("."), Expected(Failed::Float));
+        assert_eq!(r.rule("-"), Expected(Failed::Float));
+        assert_eq!(r.rule("e5"), Expected(Failed::Float));
+        assert_eq!(value(r.rule("0.1")), Some(0.1));
+        assert_eq!(value(r.rule("1e400")), Some(f64::INFINITY));
+        assert_eq!(value(r.rule("2.2250738585072011e-308")), Some(2.225_073_858_507_201e-308));
+    }
+
+    #[test]
+    fn special() {
+        let r = super::float::<f64>();
+        assert_eq!(r.rule("inf"), Match((f64::INFINITY, "inf"), ""));
+        assert_eq!(r.rule("-Infinity!"), Match((f64::NEG_INFINITY, "-Infinity"), "!"));
+        assert!(value(r.rule("NaN")).unwrap().is_nan());
+        assert_eq!(r.json().rule("inf"), Expected(Failed::Float));
+        assert_eq!(r.rule("abй"), Expected(Failed::Float));
+        assert_eq!(r.rule("iй"), Expected(Failed::Float));
+    }
+
+    #[test]
+    fn hex() {
+        let r = super::float::<f64>();
+        assert_eq!(r.rule("0x1p0"), Match((1.0, "0x1p0"), ""));
+        assert_eq!(r.rule("-0x1.8p1"), Match((-3.0, "-0x1.8p1"), ""));
+        assert_eq!(r.rule("0x.8p-1"), Match((0.25, "0x.8p-1"), ""));
+        assert_eq!(value(r.rule("0x1.fffffffffffffp1023")), Some(f64::MAX));
+        assert_eq!(value(r.rule("0x1p1024")), Some(f64::INFINITY));
+        assert_eq!(value(r.rule("0x1p-1074")), Some(f64::from_bits(1)));
+        assert_eq!(value(r.rule("0x1p-1075")), Some(0.0));
+        assert_eq!(value(r.rule("0x3p-1075")), Some(f64::from_bits(2)));
+
+        // Ties are rounded to even
+        assert_eq!(value(r.rule("0x1.00000000000008p0")), Some(1.0));
+        assert_eq!(value(r.rule("0x1.00000000000018p0")), Some(1.0 + 2.0 * f64::EPSILON));
+        assert_eq!(value(r.rule("0x1.000000000000080001p0")), Some(1.0 + f64::EPSILON));
+
+        assert_eq!(value(super::float::<f32>().rule("0x1.000001p0")), Some(1.0));
+        assert_eq!(value(super::float::<f32>().rule("0x1.000003p0")), Some(1.0 + 2.0 * f32::EPSILON));
+
+        // A hex number without an exponent isn't a float
+        assert_eq!(r.rule("0x10"), Match((0.0, "0"), "x10"));
+    }
+
+    #[test]
+    fn json() {
+        let r = super::float::<f64>().json();
+        assert_eq!(r.rule("-0.5e+2"), Match((-50.0, "-0.5e+2"), ""));
+        assert_eq!(r.rule("01"), Match((0.0, "0"), "1"));
+        assert_eq!(r.rule("1."), Match((1.0, "1"), "."));
+        assert_eq!(r.rule("+1"), Expected(Failed::Float));
+        assert_eq!(r.rule(".5"), Expected(Failed::Float));
+        assert_eq!(r.rule("1_0"), Match((1.0, "1"), "_0"));
+    }
+
+    #[test]
+    fn rust() {
+        let r = super::float::<f64>().rust();
+        assert_eq!(r.rule("1_000.000_1e1_0"), Match((1_000.000_1e1_0, "1_000.000_1e1_0"), ""));
+        assert_eq!(r.rule("1."), Match((1.0, "1."), ""));
+        assert_eq!(r.rule("1..2"), Match((1.0, "1"), "..2"));
+        assert_eq!(r.rule("1.max"), Match((1.0, "1"), ".max"));
+        assert_eq!(r.rule("_1"), Expected(Failed::Float));
+        assert_eq!(r.rule("inf"), Expected(Failed::Float));
+
+        let long = format!("0.{}1_0", "0".repeat(100));
+        assert_eq!(value(r.rule(&long)), Some(1e-101));
+    }
+}
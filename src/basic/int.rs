@@ -0,0 +1,274 @@
+use crate::prelude::*;
+
+/// An interface of integer types that [`Int`] can produce.
+///
+/// [`Int`]: ./struct.Int.html
+pub trait Integer: Copy {
+    /// `true` if the type can hold negative values.
+    const SIGNED: bool;
+
+    /// Returns zero value.
+    fn zero() -> Self;
+
+    /// Appends the digit to the number in the radix.
+    ///
+    /// The digit is subtracted if `neg` is `true`.
+    /// Returns `None` on overflow.
+    fn push_digit(self, radix: u32, digit: u32, neg: bool) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty: $signed:expr),+ $(,)?) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = $signed;
+
+                fn zero() -> Self { 0 }
+
+                fn push_digit(self, radix: u32, digit: u32, neg: bool) -> Option<Self> {
+                    use std::convert::TryFrom;
+
+                    let n = self.checked_mul(<$t>::try_from(radix).ok()?)?;
+                    let d = <$t>::try_from(digit).ok()?;
+
+                    if neg {
+                        n.checked_sub(d)
+                    } else {
+                        n.checked_add(d)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(
+    i8: true, i16: true, i32: true, i64: true, i128: true, isize: true,
+    u8: false, u16: false, u32: false, u64: false, u128: false, usize: false,
+);
+
+/// Integer number parser.
+///
+/// Accumulates digits directly into the target type without allocation.
+/// A sign is accepted for signed types only.
+/// Fails with [`Failed::Overflow`] if the number doesn't fit the type.
+/// If there are no digits, fails with a variant of the radix:
+/// [`Failed::Bin`], [`Failed::Oct`], [`Failed::Dec`], [`Failed::Hex`] or [`Failed::Digit`].
+///
+/// [`Failed::Overflow`]: ../enum.Failed.html#variant.Overflow
+/// [`Failed::Bin`]: ../enum.Failed.html#variant.Bin
+/// [`Failed::Oct`]: ../enum.Failed.html#variant.Oct
+/// [`Failed::Dec`]: ../enum.Failed.html#variant.Dec
+/// [`Failed::Hex`]: ../enum.Failed.html#variant.Hex
+/// [`Failed::Digit`]: ../enum.Failed.html#variant.Digit
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::{int, prefixed_int}};
+/// assert_eq!(int::<i64>().rule("-42;"), Match(-42, ";"));
+/// assert_eq!(int::<u8>().rule("256"), Expected(Failed::Overflow));
+/// assert_eq!(prefixed_int::<i32>().rule("0x1F"), Match(31, ""));
+/// assert_eq!(int::<u32>().separators().rule("1_000"), Match(1000, ""));
+/// ```
+#[derive(Debug)]
+pub struct Int<T> {
+    radix: u32,
+    sign: bool,
+    prefix: bool,
+    separators: bool,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T> Int<T>
+    where
+        T: Integer,
+{
+    /// Creates a rule of the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix isn't in the range `2..=36`.
+    pub fn new(radix: u32) -> Self {
+        assert!((2..=36).contains(&radix), "The radix must be in the range 2..=36");
+
+        Int {
+            radix,
+            sign: T::SIGNED,
+            prefix: false,
+            separators: false,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Allows `_` separators between digits.
+    pub fn separators(self) -> Self { Int { separators: true, ..self } }
+}
+
+impl<T> Clone for Int<T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for Int<T> {}
+
+/// Decimal integer, signed for signed types.
+pub fn int<T>() -> Int<T>
+    where
+        T: Integer,
+{ Int::new(10) }
+
+/// Decimal integer without a sign.
+pub fn uint<T>() -> Int<T>
+    where
+        T: Integer,
+{ Int { sign: false, ..Int::new(10) } }
+
+/// Integer of the radix, signed for signed types.
+///
+/// # Panics
+///
+/// Panics if the radix isn't in the range `2..=36`.
+pub fn radix_int<T>(radix: u32) -> Int<T>
+    where
+        T: Integer,
+{ Int::new(radix) }
+
+/// Integer with an optional radix prefix `0x`, `0o` or `0b`.
+///
+/// A number without the prefix is decimal.
+pub fn prefixed_int<T>() -> Int<T>
+    where
+        T: Integer,
+{ Int { prefix: true, ..Int::new(10) } }
+
+impl<'r, 'i, T> Rule<'r, &'i str> for Int<T>
+    where
+        T: Integer,
+{
+    type Mat = T;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        let bytes = input.as_bytes();
+        let mut pos = 0;
+
+        let neg = match bytes.first() {
+            Some(b'-') if self.sign => { pos += 1; true }
+            Some(b'+') if self.sign => { pos += 1; false }
+            _ => false,
+        };
+
+        let mut radix = self.radix;
+        if self.prefix {
+            let prefixed = match bytes.get(pos..pos + 2) {
+                Some(b"0x") | Some(b"0X") => Some(16),
+                Some(b"0o") | Some(b"0O") => Some(8),
+                Some(b"0b") | Some(b"0B") => Some(2),
+                _ => None,
+            };
+
+            if let Some(r) = prefixed {
+                radix = r;
+                pos += 2;
+            }
+        }
+
+        let digit = |pos: usize| bytes.get(pos).and_then(|&b| (b as char).to_digit(radix));
+
+        if digit(pos).is_none() {
+            return Expected(match radix {
+                2 => Failed::Bin,
+                8 => Failed::Oct,
+                10 => Failed::Dec,
+                16 => Failed::Hex,
+                _ => Failed::Digit(radix),
+            });
+        }
+
+        let mut num = T::zero();
+
+        loop {
+            if let Some(d) = digit(pos) {
+                num = match num.push_digit(radix, d, neg) {
+                    Some(n) => n,
+                    None => return Expected(Failed::Overflow),
+                };
+
+                pos += 1;
+                continue;
+            }
+
+            if self.separators {
+                let skip = bytes[pos..].iter().take_while(|&&b| b == b'_').count();
+                if skip > 0 && digit(pos + skip).is_some() {
+                    pos += skip;
+                    continue;
+                }
+            }
+
+            break Match(num, &input[pos..]);
+        }
+    }
+}
+
+impl_ops!(Int<N>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int() {
+        let r = super::int::<i8>();
+        assert_eq!(r.rule("12a"), Match(12, "a"));
+        assert_eq!(r.rule("+12"), Match(12, ""));
+        assert_eq!(r.rule("-128"), Match(-128, ""));
+        assert_eq!(r.rule("128"), Expected(Failed::Overflow));
+        assert_eq!(r.rule("-129"), Expected(Failed::Overflow));
+        assert_eq!(r.rule("-"), Expected(Failed::Dec));
+        assert_eq!(r.rule("a"), Expected(Failed::Dec));
+
+        let r = super::int::<u64>();
+        assert_eq!(r.rule("18446744073709551615"), Match(u64::MAX, ""));
+        assert_eq!(r.rule("18446744073709551616"), Expected(Failed::Overflow));
+        assert_eq!(r.rule("-1"), Expected(Failed::Dec));
+    }
+
+    #[test]
+    fn uint() {
+        let r = super::uint::<i32>();
+        assert_eq!(r.rule("42"), Match(42, ""));
+        assert_eq!(r.rule("-42"), Expected(Failed::Dec));
+    }
+
+    #[test]
+    fn radix_int() {
+        assert_eq!(super::radix_int::<u32>(16).rule("fF!"), Match(255, "!"));
+        assert_eq!(super::radix_int::<i32>(2).rule("-101"), Match(-5, ""));
+        assert_eq!(super::radix_int::<i32>(8).rule("8"), Expected(Failed::Oct));
+        assert_eq!(super::radix_int::<i32>(36).rule("zz"), Match(1295, ""));
+        assert_eq!(super::radix_int::<i32>(3).rule("3"), Expected(Failed::Digit(3)));
+    }
+
+    #[test]
+    fn prefixed_int() {
+        let r = super::prefixed_int::<i64>();
+        assert_eq!(r.rule("0x1F"), Match(31, ""));
+        assert_eq!(r.rule("-0b101"), Match(-5, ""));
+        assert_eq!(r.rule("0o17"), Match(15, ""));
+        assert_eq!(r.rule("42"), Match(42, ""));
+        assert_eq!(r.rule("0z"), Match(0, "z"));
+        assert_eq!(r.rule("0x"), Expected(Failed::Hex));
+    }
+
+    #[test]
+    fn separators() {
+        let r = super::int::<i32>().separators();
+        assert_eq!(r.rule("1_000_000"), Match(1_000_000, ""));
+        assert_eq!(r.rule("1__0_"), Match(10, "_"));
+        assert_eq!(r.rule("_1"), Expected(Failed::Dec));
+
+        let r = super::int::<i32>();
+        assert_eq!(r.rule("1_000"), Match(1, "_000"));
+    }
+}
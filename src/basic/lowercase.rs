@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+/// Match a lowercase char.
+///
+/// The char has the Unicode `Lowercase` property.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::lowercase};
+/// assert_eq!(lowercase().rule("жЖ"), Match("ж", "Ж"));
+/// assert!(!lowercase().test("Ж"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Lowercase;
+
+/// Constructor of [`Lowercase`]
+///
+/// [`Lowercase`]: ./struct.Lowercase.html
+pub fn lowercase() -> Lowercase { Lowercase }
+
+impl<'r, 'i> Rule<'r, &'i str> for Lowercase {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if c.is_lowercase() => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::Lowercase),
+        }
+    }
+}
+
+impl_ops!(Lowercase);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowercase() {
+        assert!(super::lowercase().test("ж"));
+        assert_eq!(super::lowercase().rule("Ж"), Expected(Failed::Lowercase));
+    }
+}
@@ -18,7 +18,7 @@ impl<'r, 'i> Rule<'r, &'i str> for Nl {
         match input.chars().next() {
             Some(c @ '\n') => input.split_at(c.len_utf8()).into(),
             Some(c @ '\r') => input.split_at(c.len_utf8()).into(),
-            _ => return Expected(Failed::Nl),
+            _ => Expected(Failed::Nl),
         }
     }
 }
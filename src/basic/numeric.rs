@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+/// Match a numeric char.
+///
+/// The char has one of the Unicode general categories `Nd`, `Nl` or `No`.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::numeric};
+/// assert!(numeric().test("7"));
+/// assert!(numeric().test("Ⅻ"));
+/// assert!(!numeric().test("x"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Numeric;
+
+/// Constructor of [`Numeric`]
+///
+/// [`Numeric`]: ./struct.Numeric.html
+pub fn numeric() -> Numeric { Numeric }
+
+impl<'r, 'i> Rule<'r, &'i str> for Numeric {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if c.is_numeric() => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::Numeric),
+        }
+    }
+}
+
+impl_ops!(Numeric);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric() {
+        assert!(super::numeric().test("7"));
+        assert!(super::numeric().test("Ⅻ"));
+        assert_eq!(super::numeric().rule("x"), Expected(Failed::Numeric));
+    }
+}
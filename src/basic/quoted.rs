@@ -0,0 +1,311 @@
+use std::borrow::Cow;
+
+use crate::prelude::*;
+
+/// A dialect of quoted strings for [`Quoted`].
+///
+/// [`Quoted`]: ./struct.Quoted.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Quoting {
+    /// JSON string in `"`.
+    ///
+    /// Escapes: `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX`
+    /// with surrogate pairs. Raw control chars are not allowed.
+    Json,
+
+    /// Rust string literal in `"`.
+    ///
+    /// Escapes: `\"`, `\'`, `\\`, `\0`, `\n`, `\r`, `\t`, `\xNN` up to `\x7F`,
+    /// `\u{N...}` and a line continuation (`\` before a newline skips
+    /// the following whitespace).
+    Rust,
+
+    /// C string literal in `"`.
+    ///
+    /// Escapes: `\"`, `\'`, `\?`, `\\`, `\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v`,
+    /// octal `\NNN`, `\xNN...`, `\uXXXX` and `\UXXXXXXXX`.
+    /// Octal and hex escapes produce the char of the code.
+    C,
+
+    /// Shell string in `'`. There are no escapes.
+    ShellSingle,
+
+    /// Shell string in `"`.
+    ///
+    /// A backslash escapes only `$`, `` ` ``, `"`, `\` and a newline,
+    /// an escaped newline is removed. Other backslashes are kept as is.
+    ShellDouble,
+}
+
+impl Quoting {
+    fn quote(self) -> char {
+        match self {
+            Quoting::ShellSingle => '\'',
+            _ => '"',
+        }
+    }
+}
+
+/// Quoted string parser.
+///
+/// Decodes escapes of the [dialect] and returns the contents without quotes.
+/// The result borrows the input if there are no escapes.
+/// Fails with [`Failed::Char`] of the quote if the string isn't opened or closed,
+/// and with [`Failed::Escape`] holding the byte position of an invalid escape.
+///
+/// [dialect]: ./enum.Quoting.html
+/// [`Failed::Char`]: ../enum.Failed.html#variant.Char
+/// [`Failed::Escape`]: ../enum.Failed.html#variant.Escape
+///
+/// # Examples
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use parsers::{prelude::*, basic::{quoted, Quoting}};
+/// let json = quoted(Quoting::Json);
+/// assert_eq!(json.rule(r#""abc","#), Match(Cow::Borrowed("abc"), ","));
+/// assert_eq!(json.rule(r#""a\n😀""#), Match(Cow::Owned("a\n😀".to_string()), ""));
+/// assert_eq!(json.rule(r#""a\q""#), Expected(Failed::Escape(2)));
+/// assert_eq!(json.rule(r#""abc"#), Expected(Failed::Char('"')));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Quoted(pub Quoting);
+
+/// [`Quoted`] constructor. See [`Quoted`] for details.
+///
+/// [`Quoted`]: ./struct.Quoted.html
+pub fn quoted(quoting: Quoting) -> Quoted { Quoted(quoting) }
+
+/// Reads exactly `n` hex digits.
+fn hex_code(s: &str, n: usize) -> Option<u32> {
+    let digits = s.get(..n)?;
+
+    if digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        u32::from_str_radix(digits, 16).ok()
+    } else {
+        None
+    }
+}
+
+/// Reads from 1 to `max` digits of the radix. Returns the code and the length.
+fn code(s: &str, radix: u32, max: usize) -> Option<(u32, usize)> {
+    let len = s.bytes()
+        .take(max)
+        .take_while(|&b| (b as char).is_digit(radix))
+        .count();
+
+    if len == 0 {
+        return None;
+    }
+
+    u32::from_str_radix(&s[..len], radix).ok().map(|c| (c, len))
+}
+
+impl Quoting {
+    /// Decodes the escape after a backslash.
+    /// Returns the char, if any, and the length of the escape.
+    fn escape(self, s: &str) -> Option<(Option<char>, usize)> {
+        let c = s.chars().next()?;
+        let simple = |c| Some((Some(c), 1));
+
+        match (self, c) {
+            (Quoting::ShellSingle, _) => None,
+
+            (Quoting::ShellDouble, '$') | (Quoting::ShellDouble, '`')
+            | (Quoting::ShellDouble, '"') | (Quoting::ShellDouble, '\\') => simple(c),
+            (Quoting::ShellDouble, '\n') => Some((None, 1)),
+            (Quoting::ShellDouble, _) => Some((Some('\\'), 0)),
+
+            (_, '"') | (_, '\\') => simple(c),
+            (_, 'n') => simple('\n'),
+            (_, 'r') => simple('\r'),
+            (_, 't') => simple('\t'),
+
+            (Quoting::Json, '/') => simple('/'),
+            (Quoting::Json, 'b') => simple('\u{8}'),
+            (Quoting::Json, 'f') => simple('\u{c}'),
+            (Quoting::Json, 'u') => {
+                let hi = hex_code(&s[1..], 4)?;
+
+                match hi {
+                    0xD800..=0xDBFF => {
+                        let lo = s[5..].strip_prefix("\\u")
+                            .and_then(|s| hex_code(s, 4))
+                            .filter(|lo| (0xDC00..=0xDFFF).contains(lo))?;
+
+                        let c = 0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00);
+                        Some((std::char::from_u32(c), 11))
+                    }
+                    _ => std::char::from_u32(hi).map(|c| (Some(c), 5)),
+                }
+            }
+
+            (Quoting::Rust, '\'') => simple('\''),
+            (Quoting::Rust, '0') => simple('\0'),
+            (Quoting::Rust, 'x') => hex_code(&s[1..], 2)
+                .filter(|&c| c <= 0x7F)
+                .map(|c| (std::char::from_u32(c), 3)),
+            (Quoting::Rust, 'u') => {
+                let body = s[1..].strip_prefix('{')?;
+                let end = body.find('}')?;
+                let digits = &body[..end];
+
+                if digits.is_empty() || digits.len() > 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return None;
+                }
+
+                let c = std::char::from_u32(u32::from_str_radix(digits, 16).ok()?)?;
+                Some((Some(c), end + 3))
+            }
+            (Quoting::Rust, '\n') | (Quoting::Rust, '\r') => {
+                let skip = s.find(|c: char| !c.is_whitespace()).unwrap_or(s.len());
+                Some((None, skip))
+            }
+
+            (Quoting::C, '\'') | (Quoting::C, '?') => simple(c),
+            (Quoting::C, 'a') => simple('\u{7}'),
+            (Quoting::C, 'b') => simple('\u{8}'),
+            (Quoting::C, 'f') => simple('\u{c}'),
+            (Quoting::C, 'v') => simple('\u{b}'),
+            (Quoting::C, '0'..='7') => code(s, 8, 3).map(|(c, len)| (std::char::from_u32(c), len)),
+            (Quoting::C, 'x') => code(&s[1..], 16, 8)
+                .and_then(|(c, len)| std::char::from_u32(c).map(|c| (Some(c), len + 1))),
+            (Quoting::C, 'u') => hex_code(&s[1..], 4)
+                .and_then(std::char::from_u32)
+                .map(|c| (Some(c), 5)),
+            (Quoting::C, 'U') => hex_code(&s[1..], 8)
+                .and_then(std::char::from_u32)
+                .map(|c| (Some(c), 9)),
+
+            _ => None,
+        }
+    }
+}
+
+impl<'r, 'i> Rule<'r, &'i str> for Quoted {
+    type Mat = Cow<'i, str>;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        let dialect = self.0;
+        let quote = dialect.quote();
+
+        if !input.starts_with(quote) {
+            return Expected(Failed::Char(quote));
+        }
+
+        let mut owned: Option<String> = None;
+        let mut start = 1;
+        let mut pos = 1;
+
+        while let Some(c) = input[pos..].chars().next() {
+            if c == quote {
+                let value = match owned {
+                    Some(mut s) => {
+                        s.push_str(&input[start..pos]);
+                        Cow::Owned(s)
+                    }
+                    None => Cow::Borrowed(&input[1..pos]),
+                };
+
+                return Match(value, &input[pos + 1..]);
+            }
+
+            if dialect == Quoting::Json && (c as u32) < 0x20 {
+                return Expected(Failed::Escape(pos));
+            }
+
+            if c == '\\' && dialect != Quoting::ShellSingle {
+                let (decoded, len) = match dialect.escape(&input[pos + 1..]) {
+                    Some(e) => e,
+                    None if pos + 1 == input.len() => break,
+                    None => return Expected(Failed::Escape(pos)),
+                };
+
+                let s = owned.get_or_insert_with(String::new);
+                s.push_str(&input[start..pos]);
+                s.extend(decoded);
+
+                pos += 1 + len;
+                start = pos;
+                continue;
+            }
+
+            pos += c.len_utf8();
+        }
+
+        Expected(Failed::Char(quote))
+    }
+}
+
+impl_ops!(Quoted);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(s: &str) -> Cow<'_, str> { Cow::Owned(s.to_string()) }
+
+    #[test]
+    fn json() {
+        let r = quoted(Quoting::Json);
+        assert_eq!(r.rule(r#""profile""#), Match(Cow::Borrowed("profile"), ""));
+        assert_eq!(r.rule(r#""profile\"",1"#), Match(owned("profile\""), ",1"));
+        assert_eq!(r.rule(r#""\\\/\b\f\n\r\t""#), Match(owned("\\/\u{8}\u{c}\n\r\t"), ""));
+        assert_eq!(r.rule(r#""\u0041\u00e9""#), Match(owned("Aé"), ""));
+        assert_eq!(r.rule(r#""\uD83D\uDE00""#), Match(owned("😀"), ""));
+        assert_eq!(r.rule(r#""ab\uD83D""#), Expected(Failed::Escape(3)));
+        assert_eq!(r.rule(r#""\uDE00""#), Expected(Failed::Escape(1)));
+        assert_eq!(r.rule(r#""\u12""#), Expected(Failed::Escape(1)));
+        assert_eq!(r.rule(r#""\x41""#), Expected(Failed::Escape(1)));
+        assert_eq!(r.rule("\"a\nb\""), Expected(Failed::Escape(2)));
+        assert_eq!(r.rule(r#""abc"#), Expected(Failed::Char('"')));
+        assert_eq!(r.rule(r#""abc\"#), Expected(Failed::Char('"')));
+        assert_eq!(r.rule("abc"), Expected(Failed::Char('"')));
+    }
+
+    #[test]
+    fn borrowed() {
+        let r = quoted(Quoting::Json);
+        assert!(matches!(r.rule(r#""abc""#), Match(Cow::Borrowed("abc"), "")));
+        assert!(matches!(r.rule(r#""a\tc""#), Match(Cow::Owned(_), "")));
+
+        let r = quoted(Quoting::ShellSingle);
+        assert!(matches!(r.rule(r"'a\n'"), Match(Cow::Borrowed(r"a\n"), "")));
+    }
+
+    #[test]
+    fn rust() {
+        let r = quoted(Quoting::Rust);
+        assert_eq!(r.rule(r#""a\'\0\x41""#), Match(owned("a'\0A"), ""));
+        assert_eq!(r.rule(r#""\u{1F600}\u{e9}""#), Match(owned("😀é"), ""));
+        assert_eq!(r.rule("\"a\\\n    b\""), Match(owned("ab"), ""));
+        assert_eq!(r.rule("\"a\nb\""), Match(Cow::Borrowed("a\nb"), ""));
+        assert_eq!(r.rule(r#""\x80""#), Expected(Failed::Escape(1)));
+        assert_eq!(r.rule(r#""\u{D800}""#), Expected(Failed::Escape(1)));
+        assert_eq!(r.rule(r#""\u{}""#), Expected(Failed::Escape(1)));
+        assert_eq!(r.rule(r#""\/""#), Expected(Failed::Escape(1)));
+    }
+
+    #[test]
+    fn c() {
+        let r = quoted(Quoting::C);
+        assert_eq!(r.rule(r#""\a\v\?\101\0""#), Match(owned("\u{7}\u{b}?A\0"), ""));
+        assert_eq!(r.rule(r#""\x41é\U0001F600""#), Match(owned("Aé😀"), ""));
+        assert_eq!(r.rule(r#""\1234""#), Match(owned("S4"), ""));
+        assert_eq!(r.rule(r#""\x""#), Expected(Failed::Escape(1)));
+        assert_eq!(r.rule(r#""\8""#), Expected(Failed::Escape(1)));
+    }
+
+    #[test]
+    fn shell() {
+        let r = quoted(Quoting::ShellSingle);
+        assert_eq!(r.rule(r"'a\n' b"), Match(Cow::Borrowed(r"a\n"), " b"));
+        assert_eq!(r.rule(r#""a""#), Expected(Failed::Char('\'')));
+
+        let r = quoted(Quoting::ShellDouble);
+        assert_eq!(r.rule(r#""\$HOME \"x\" \n""#), Match(owned(r#"$HOME "x" \n"#), ""));
+        assert_eq!(r.rule("\"a\\\nb\""), Match(owned("ab"), ""));
+        assert_eq!(r.rule(r#""a\""#), Expected(Failed::Char('"')));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+/// Match a whitespace char.
+///
+/// The char has the Unicode `White_Space` property.
+/// Unlike [`White`], it matches a single char, so `\r\n` is two matches.
+///
+/// [`White`]: ./struct.White.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::unicode_white};
+/// assert!(unicode_white().test("\u{a0}"));
+/// assert_eq!(unicode_white().rule("\r\n"), Match("\r", "\n"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct UnicodeWhite;
+
+/// Constructor of [`UnicodeWhite`]
+///
+/// [`UnicodeWhite`]: ./struct.UnicodeWhite.html
+pub fn unicode_white() -> UnicodeWhite { UnicodeWhite }
+
+impl<'r, 'i> Rule<'r, &'i str> for UnicodeWhite {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if c.is_whitespace() => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::UnicodeWhite),
+        }
+    }
+}
+
+impl_ops!(UnicodeWhite);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_white() {
+        assert!(super::unicode_white().test("\u{a0}"));
+        assert!(super::unicode_white().test("\u{2003}"));
+        assert_eq!(super::unicode_white().rule("\r\n"), Match("\r", "\n"));
+        assert_eq!(super::unicode_white().rule("a"), Expected(Failed::UnicodeWhite));
+    }
+}
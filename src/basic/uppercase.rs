@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+/// Match an uppercase char.
+///
+/// The char has the Unicode `Uppercase` property.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::uppercase};
+/// assert_eq!(uppercase().rule("Жж"), Match("Ж", "ж"));
+/// assert!(!uppercase().test("ж"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Uppercase;
+
+/// Constructor of [`Uppercase`]
+///
+/// [`Uppercase`]: ./struct.Uppercase.html
+pub fn uppercase() -> Uppercase { Uppercase }
+
+impl<'r, 'i> Rule<'r, &'i str> for Uppercase {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if c.is_uppercase() => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::Uppercase),
+        }
+    }
+}
+
+impl_ops!(Uppercase);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uppercase() {
+        assert!(super::uppercase().test("Ж"));
+        assert_eq!(super::uppercase().rule("ж"), Expected(Failed::Uppercase));
+    }
+}
@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+/// Match a char that can continue an identifier.
+///
+/// The char has the Unicode `XID_Continue` property.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::xid_continue};
+/// assert!(xid_continue().test("_"));
+/// assert!(xid_continue().test("1"));
+/// assert!(!xid_continue().test("-"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct XidContinue;
+
+/// Constructor of [`XidContinue`]
+///
+/// [`XidContinue`]: ./struct.XidContinue.html
+pub fn xid_continue() -> XidContinue { XidContinue }
+
+impl<'r, 'i> Rule<'r, &'i str> for XidContinue {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if unicode_ident::is_xid_continue(c) => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::XidContinue),
+        }
+    }
+}
+
+impl_ops!(XidContinue);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xid_continue() {
+        assert!(super::xid_continue().test("_"));
+        assert!(super::xid_continue().test("1"));
+        assert_eq!(super::xid_continue().rule("-"), Expected(Failed::XidContinue));
+    }
+}
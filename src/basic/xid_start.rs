@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// Match a char that can start an identifier.
+///
+/// The char has the Unicode `XID_Start` property.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::xid_start};
+/// assert!(xid_start().test("λ"));
+/// assert!(!xid_start().test("_"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct XidStart;
+
+/// Constructor of [`XidStart`]
+///
+/// [`XidStart`]: ./struct.XidStart.html
+pub fn xid_start() -> XidStart { XidStart }
+
+impl<'r, 'i> Rule<'r, &'i str> for XidStart {
+    type Mat = &'i str;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if unicode_ident::is_xid_start(c) => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::XidStart),
+        }
+    }
+}
+
+impl_ops!(XidStart);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xid_start() {
+        assert!(super::xid_start().test("λ"));
+        assert_eq!(super::xid_start().rule("_"), Expected(Failed::XidStart));
+        assert_eq!(super::xid_start().rule("1"), Expected(Failed::XidStart));
+    }
+}
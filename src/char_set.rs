@@ -0,0 +1,363 @@
+use crate::{
+    prelude::*,
+    CharRange,
+    basic::*,
+};
+
+const MAX: u32 = char::MAX as u32;
+const SURROGATES: (u32, u32) = (0xD800, 0xDFFF);
+
+/// Char set parser.
+///
+/// Checks that the input `char` belongs to the set.
+/// A set is built from strings, [char ranges] or [basic] classes
+/// and combined with [`union`], [`intersection`], [`difference`] and [`complement`].
+/// Use [`char_set`] to create a set from the chars of a string.
+///
+/// ASCII chars are looked up in a bitmap, the others
+/// are found by binary search among sorted ranges.
+///
+/// [char ranges]: ./struct.CharRange.html
+/// [basic]: ./basic/index.html
+/// [`union`]: ./struct.CharSet.html#method.union
+/// [`intersection`]: ./struct.CharSet.html#method.intersection
+/// [`difference`]: ./struct.CharSet.html#method.difference
+/// [`complement`]: ./struct.CharSet.html#method.complement
+/// [`char_set`]: ./fn.char_set.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, CharSet, basic::latin};
+/// // Match a latin letter except vowels
+/// let rule = CharSet::from(latin()).difference(&char_set("aeiouAEIOU"));
+///
+/// assert!(rule.rule("b").is_match());
+/// assert!(rule.rule("a").is_expected());
+/// assert!(rule.rule("1").is_expected());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CharSet {
+    ascii: [u64; 2],
+    ranges: Vec<(u32, u32)>,
+}
+
+/// [`CharSet`] constructor from chars of the string. See [`CharSet`] for details.
+///
+/// [`CharSet`]: ./struct.CharSet.html
+pub fn char_set(chars: &str) -> CharSet { chars.into() }
+
+impl CharSet {
+    /// Creates an empty set.
+    pub fn new() -> Self { CharSet::default() }
+
+    /// Creates a set of all chars.
+    pub fn full() -> Self { CharSet::from_ranges(vec![(0, MAX)]) }
+
+    /// Creates a set of all chars satisfying the predicate.
+    ///
+    /// Note that the predicate is called for every `char` value, which takes a while.
+    /// Sets of the Unicode [basic] classes are built once and then cloned.
+    ///
+    /// [basic]: ./basic/index.html
+    pub fn from_fn<F>(f: F) -> Self
+        where
+            F: Fn(char) -> bool,
+    {
+        let mut ranges: Vec<(u32, u32)> = Vec::new();
+
+        for c in (0..=MAX).filter_map(std::char::from_u32).filter(|&c| f(c)) {
+            let c = c as u32;
+
+            match ranges.last_mut() {
+                Some((_, to)) if *to + 1 == c => *to = c,
+                _ => ranges.push((c, c)),
+            }
+        }
+
+        CharSet::from_ranges(ranges)
+    }
+
+    fn from_ranges(mut ranges: Vec<(u32, u32)>) -> Self {
+        ranges.retain(|&(from, to)| from <= to);
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (from, to) in ranges {
+            match merged.last_mut() {
+                Some((_, last)) if from <= last.saturating_add(1) => *last = to.max(*last),
+                _ => merged.push((from, to)),
+            }
+        }
+
+        let mut set = CharSet {
+            ascii: [0; 2],
+            ranges: merged,
+        };
+        set.remove_surrogates();
+        set.fill_ascii();
+        set
+    }
+
+    fn remove_surrogates(&mut self) {
+        let (lo, hi) = SURROGATES;
+
+        self.ranges = self.ranges.iter()
+            .flat_map(|&(from, to)| {
+                let before = (from, to.min(lo - 1));
+                let after = (from.max(hi + 1), to);
+                std::iter::once(before).chain(std::iter::once(after))
+            })
+            .filter(|&(from, to)| from <= to)
+            .collect();
+    }
+
+    fn fill_ascii(&mut self) {
+        self.ascii = [0; 2];
+
+        for &(from, to) in self.ranges.iter().take_while(|&&(from, _)| from < 128) {
+            for c in from..=to.min(127) {
+                self.ascii[c as usize / 64] |= 1 << (c % 64);
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains the char.
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+
+        if c < 128 {
+            return self.ascii[c as usize / 64] & (1 << (c % 64)) != 0;
+        }
+
+        self.ranges
+            .binary_search_by(|&(from, to)| {
+                use std::cmp::Ordering;
+
+                if to < c {
+                    Ordering::Less
+                } else if from > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns `true` if the set contains no chars.
+    pub fn is_empty(&self) -> bool { self.ranges.is_empty() }
+
+    /// Returns a set of chars contained in `self` or `other`.
+    pub fn union(&self, other: &CharSet) -> CharSet {
+        let ranges = self.ranges.iter()
+            .chain(&other.ranges)
+            .copied()
+            .collect();
+
+        CharSet::from_ranges(ranges)
+    }
+
+    /// Returns a set of chars contained in both `self` and `other`.
+    pub fn intersection(&self, other: &CharSet) -> CharSet {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+
+        while let (Some(&&(a_from, a_to)), Some(&&(b_from, b_to))) = (a.peek(), b.peek()) {
+            let from = a_from.max(b_from);
+            let to = a_to.min(b_to);
+
+            if from <= to {
+                ranges.push((from, to));
+            }
+
+            if a_to < b_to {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        CharSet::from_ranges(ranges)
+    }
+
+    /// Returns a set of chars contained in `self` but not in `other`.
+    pub fn difference(&self, other: &CharSet) -> CharSet { self.intersection(&other.complement()) }
+
+    /// Returns a set of chars not contained in `self`.
+    pub fn complement(&self) -> CharSet {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next = 0;
+
+        for &(from, to) in &self.ranges {
+            if from > next {
+                ranges.push((next, from - 1));
+            }
+
+            next = to + 1;
+        }
+
+        if next <= MAX {
+            ranges.push((next, MAX));
+        }
+
+        CharSet::from_ranges(ranges)
+    }
+}
+
+impl From<&str> for CharSet {
+    fn from(chars: &str) -> Self {
+        CharSet::from_ranges(chars.chars().map(|c| (c as u32, c as u32)).collect())
+    }
+}
+
+impl From<char> for CharSet {
+    fn from(c: char) -> Self { CharSet::from_ranges(vec![(c as u32, c as u32)]) }
+}
+
+impl From<CharRange> for CharSet {
+    fn from(rng: CharRange) -> Self {
+        use std::ops::Bound::*;
+
+        let from = match rng.from {
+            Included(c) => c as u32,
+            Excluded(c) => c as u32 + 1,
+            Unbounded => 0,
+        };
+
+        let to = match rng.to {
+            Included(c) => c as u32,
+            Excluded(c) => match (c as u32).checked_sub(1) {
+                Some(to) => to,
+                None => return CharSet::new(),
+            },
+            Unbounded => MAX,
+        };
+
+        CharSet::from_ranges(vec![(from, to)])
+    }
+}
+
+macro_rules! impl_from_class {
+    ($t:ident, $chars:expr) => {
+        impl From<$t> for CharSet {
+            fn from(_: $t) -> Self { $chars }
+        }
+    };
+    (once $t:ident, $chars:expr) => {
+        impl From<$t> for CharSet {
+            fn from(_: $t) -> Self {
+                static SET: std::sync::OnceLock<CharSet> = std::sync::OnceLock::new();
+                SET.get_or_init(|| $chars).clone()
+            }
+        }
+    };
+}
+
+impl_from_class!(Any, CharSet::full());
+impl_from_class!(Bin, char_range('0'..='1').into());
+impl_from_class!(Oct, char_range('0'..='7').into());
+impl_from_class!(Dec, char_range('0'..='9').into());
+impl_from_class!(Hex, CharSet::from(dec()).union(&char_set("abcdefABCDEF")));
+impl_from_class!(Latin, CharSet::from(char_range('a'..='z')).union(&char_range('A'..='Z').into()));
+impl_from_class!(Space, ' '.into());
+impl_from_class!(White, char_set(" \n\r\t"));
+impl_from_class!(Nl, char_set("\n\r"));
+impl_from_class!(once Alphabetic, CharSet::from_fn(char::is_alphabetic));
+impl_from_class!(once Alphanumeric, CharSet::from_fn(char::is_alphanumeric));
+impl_from_class!(once Numeric, CharSet::from_fn(char::is_numeric));
+impl_from_class!(once Uppercase, CharSet::from_fn(char::is_uppercase));
+impl_from_class!(once Lowercase, CharSet::from_fn(char::is_lowercase));
+impl_from_class!(once UnicodeWhite, CharSet::from_fn(char::is_whitespace));
+impl_from_class!(once Control, CharSet::from_fn(char::is_control));
+impl_from_class!(once XidStart, CharSet::from_fn(unicode_ident::is_xid_start));
+impl_from_class!(once XidContinue, CharSet::from_fn(unicode_ident::is_xid_continue));
+
+impl<'r, 'i> Rule<'r, &'i str> for CharSet {
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match input.chars().next() {
+            Some(c) if self.contains(c) => input.split_at(c.len_utf8()).into(),
+            _ => Expected(Failed::CharSet(self)),
+        }
+    }
+}
+
+impl_ops!(CharSet);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_set() {
+        let r = super::char_set("+-*/");
+        assert_eq!(r.rule("+1"), Match("+", "1"));
+        assert_eq!(r.rule("/"), Match("/", ""));
+        assert_eq!(r.rule("1"), Expected(Failed::CharSet(&r)));
+        assert_eq!(r.rule(""), Expected(Failed::CharSet(&r)));
+
+        let r = super::char_set("жы");
+        assert_eq!(r.rule("ы"), Match("ы", ""));
+        assert!(r.rule("ш").is_expected());
+    }
+
+    #[test]
+    fn algebra() {
+        let letters = CharSet::from(latin());
+        let hex = CharSet::from(hex());
+
+        let r = letters.intersection(&hex);
+        assert!(r.test("f"));
+        assert!(r.test("A"));
+        assert!(!r.test("g"));
+        assert!(!r.test("1"));
+
+        let r = letters.union(&dec().into());
+        assert!(r.test("q"));
+        assert!(r.test("5"));
+        assert!(!r.test("_"));
+
+        let r = letters.difference(&hex);
+        assert!(r.test("g"));
+        assert!(!r.test("a"));
+
+        let r = hex.complement();
+        assert!(r.test("g"));
+        assert!(r.test("字"));
+        assert!(!r.test("0"));
+        assert_eq!(r.complement(), hex);
+
+        assert!(CharSet::new().complement().test("\u{10FFFF}"));
+        assert!(CharSet::full().difference(&CharSet::full()).is_empty());
+    }
+
+    #[test]
+    fn from_char_range() {
+        let r = CharSet::from(char_range('b'..'d'));
+        assert!(r.test("b"));
+        assert!(r.test("c"));
+        assert!(!r.test("d"));
+
+        let r = CharSet::from(char_range(..));
+        assert_eq!(r, CharSet::full());
+
+        let r = CharSet::from(char_range('\u{D7FF}'..='\u{E000}'));
+        assert_eq!(r, super::char_set("\u{D7FF}\u{E000}"));
+    }
+
+    #[test]
+    fn from_class() {
+        let r = CharSet::from(alphabetic()).union(&'_'.into());
+        assert!(r.test("ж"));
+        assert!(r.test("_"));
+        assert!(!r.test("1"));
+        assert_eq!(CharSet::from(alphabetic()), CharSet::from(alphabetic()));
+
+        let r = CharSet::from(white());
+        assert!(r.test("\t"));
+        assert!(!r.test("\u{a0}"));
+    }
+}
@@ -51,6 +51,7 @@ impl<'r, I, A, B, C> Rule<'r, I> for Cat<A, B, C>
 impl_or!(Cat<A, B, C>);
 impl_shifts!(Cat<A, B, C>);
 impl_not!(Cat<A, B, C>);
+impl_sub!(Cat<A, B, C>);
 
 impl<A, B, T> std::ops::BitAnd<T> for Cat<A, B, &'static str> {
     type Output = Cat<Cat<A, B, &'static str>, T, &'static str>;
@@ -0,0 +1,66 @@
+use crate::{
+    prelude::*,
+    Consumed,
+};
+
+/// Difference of rules.
+///
+/// Fails with [`Failed::Unexpected`] containing the length of the excluded match
+/// if the second rule matches at the current position, otherwise applies the first one.
+/// The first rule must fail with [`Failed`], its error is returned as is.
+/// Created with [`Rule::except`] or the `-` operator.
+///
+/// [`Failed::Unexpected`]: ../enum.Failed.html#variant.Unexpected
+/// [`Failed`]: ../enum.Failed.html
+/// [`Rule::except`]: ../trait.Rule.html#method.except
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::any, compound::take_while1};
+/// let body = any() - '"';
+/// assert_eq!(body.rule("a\""), Match("a", "\""));
+/// assert_eq!(body.rule("\""), Expected(Failed::Unexpected(1)));
+///
+/// let ident = take_while1(char::is_alphabetic).except(("if", ' '));
+/// assert_eq!(ident.rule("iffy "), Match("iffy", " "));
+/// assert_eq!(ident.rule("if "), Expected(Failed::Unexpected(3)));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Except<A, B>(pub A, pub B);
+
+impl<'r, 'e, I, A, B> Rule<'r, I> for Except<A, B>
+    where
+        A: Rule<'r, I, Exp = Failed<'e>>,
+        B: Rule<'r, I>,
+        I: Consumed,
+{
+    type Mat = A::Mat;
+    type Exp = Failed<'e>;
+
+    fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+        match self.1.rule(input.clone()) {
+            Match(_, rest) => Expected(Failed::Unexpected(input.rest_len() - rest.rest_len())),
+            Expected(_) => self.0.rule(input),
+        }
+    }
+}
+
+impl_ops!(Except<A, B>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::{any, dec};
+
+    #[test]
+    fn except() {
+        let r = any().except(dec());
+        assert_eq!(r.rule("a1"), Match("a", "1"));
+        assert_eq!(r.rule("1a"), Expected(Failed::Unexpected(1)));
+        assert_eq!(r.rule(""), Expected(Failed::AnyChar));
+
+        let r = (char_range('a'..='z') - 'x' - 'y').range::<Vec<_>, _>(..);
+        assert_eq!(r.rule("abxc"), Match(vec!["a", "b"], "xc"));
+    }
+}
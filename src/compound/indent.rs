@@ -0,0 +1,361 @@
+use crate::{
+    prelude::*,
+    Stateful,
+};
+
+/// A policy of tabs in indentation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Tabs {
+    /// A tab advances the indentation to the next multiple of the width.
+    /// A width of 0 is treated as 1.
+    Width(usize),
+
+    /// Tabs are not allowed in indentation.
+    Forbid,
+}
+
+impl Default for Tabs {
+    fn default() -> Self { Tabs::Width(8) }
+}
+
+/// The indentation stack for indentation-sensitive parsing.
+///
+/// Keep it in the [`State`] to use [`indented`], [`same_indent`] and [`dedent`].
+/// The state type must implement `AsRef<Indents>` and `AsMut<Indents>`,
+/// so the stack can be a part of a larger user state.
+///
+/// The state type is a part of the types of [`same_indent`] and [`dedent`] rules,
+/// so it's inferred from the input the rules are applied to.
+///
+/// Indentation is the spaces and tabs at the start of a line.
+/// Lines containing only spaces and tabs are skipped while measuring it.
+///
+/// [`State`]: ../struct.State.html
+/// [`indented`]: ./fn.indented.html
+/// [`same_indent`]: ./fn.same_indent.html
+/// [`dedent`]: ./fn.dedent.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, State, compound::*};
+/// let line = (same_indent(), lift(char_range('a'..='z')), lift('\n'.opt()));
+/// let block = indented(line.range::<Vec<_>, _>(1..));
+///
+/// let state = State::new(Indents::new());
+/// match (line, block).rule(state.input("a\n  b\n  c\nd")) {
+///     Match((_, children), rest) => {
+///         assert_eq!(children.len(), 2);
+///         assert_eq!(rest.input(), "d");
+///     }
+///     Expected(_) => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Indents {
+    stack: Vec<usize>,
+    tabs: Tabs,
+}
+
+impl Indents {
+    /// Creates an empty stack with the default tab width of 8.
+    pub fn new() -> Self { Indents::default() }
+
+    /// Sets the policy of tabs.
+    pub fn tabs(self, tabs: Tabs) -> Self {
+        let tabs = match tabs {
+            Tabs::Width(0) => Tabs::Width(1),
+            tabs => tabs,
+        };
+
+        Indents { tabs, ..self }
+    }
+
+    /// Returns the current indentation width.
+    pub fn current(&self) -> usize { self.stack.last().copied().unwrap_or(0) }
+
+    /// Returns the number of opened blocks.
+    pub fn depth(&self) -> usize { self.stack.len() }
+
+    /// Measures the indentation of the next non-blank line.
+    ///
+    /// Returns the width and the byte offsets of the line start and the indentation end,
+    /// `Ok(None)` at the end of input and `Err(())` on a forbidden tab.
+    fn measure(&self, input: &str) -> Result<Option<(usize, usize, usize)>, ()> {
+        let bytes = input.as_bytes();
+        let mut line = 0;
+
+        loop {
+            let mut width = 0;
+            let mut pos = line;
+
+            while let Some(&b) = bytes.get(pos) {
+                match (b, self.tabs) {
+                    (b' ', _) => width += 1,
+                    (b'\t', Tabs::Width(n)) => width = (width / n + 1) * n,
+                    (b'\t', Tabs::Forbid) => return Err(()),
+                    _ => break,
+                }
+
+                pos += 1;
+            }
+
+            match bytes.get(pos) {
+                None => return Ok(None),
+                Some(b'\r') if bytes.get(pos + 1) == Some(&b'\n') => line = pos + 2,
+                Some(b'\n') | Some(b'\r') => line = pos + 1,
+                Some(_) => return Ok(Some((width, line, pos))),
+            }
+        }
+    }
+}
+
+impl AsRef<Indents> for Indents {
+    fn as_ref(&self) -> &Indents { self }
+}
+
+impl AsMut<Indents> for Indents {
+    fn as_mut(&mut self) -> &mut Indents { self }
+}
+
+/// Matches a block indented deeper than the current level.
+///
+/// Doesn't consume the indentation of the first line, so the block can
+/// start with [`same_indent`]. Fails with [`Failed::Indent`] if the block isn't indented deeper.
+///
+/// [`same_indent`]: ./fn.same_indent.html
+/// [`Failed::Indent`]: ../enum.Failed.html#variant.Indent
+#[derive(Copy, Clone, Debug)]
+pub struct Indented<R>(pub R);
+
+/// [`Indented`] constructor. See [`Indented`] and [`Indents`] for details.
+///
+/// [`Indented`]: ./struct.Indented.html
+/// [`Indents`]: ./struct.Indents.html
+pub fn indented<R>(block: R) -> Indented<R> { Indented(block) }
+
+impl<'r, 'i, S, R> Rule<'r, Stateful<&'i str, S>> for Indented<R>
+    where
+        R: Rule<'r, Stateful<&'i str, S>>,
+        R::Exp: Into<Failed<'r>>,
+        S: AsRef<Indents> + AsMut<Indents> + Clone,
+{
+    type Mat = R::Mat;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: Stateful<&'i str, S>) -> Ruled<Stateful<&'i str, S>, Self::Mat, Self::Exp> {
+        let width = match input.with(|s| {
+            let indents = s.as_ref();
+            indents.measure(input.input()).map(|m| (m, indents.current()))
+        }) {
+            Ok((Some((width, _, _)), current)) if width > current => width,
+            _ => return Expected(Failed::Indent),
+        };
+
+        let inner = input.update(|s| s.as_mut().stack.push(width));
+
+        self.0.rule(inner)
+            .map_exp(|e| e.into())
+            .and_then(|m, rest| Match(m, rest.update(|s| { s.as_mut().stack.pop(); })))
+    }
+}
+
+impl_ops!(Indented<R>);
+
+/// Matches the indentation equal to the current level.
+///
+/// Skips blank lines and consumes the indentation.
+/// Fails with [`Failed::Indent`] otherwise.
+///
+/// [`Failed::Indent`]: ../enum.Failed.html#variant.Indent
+#[derive(Debug)]
+pub struct SameIndent<S>(std::marker::PhantomData<S>);
+
+impl<S> Clone for SameIndent<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<S> Copy for SameIndent<S> {}
+
+/// [`SameIndent`] constructor. See [`SameIndent`] and [`Indents`] for details.
+///
+/// [`SameIndent`]: ./struct.SameIndent.html
+/// [`Indents`]: ./struct.Indents.html
+pub fn same_indent<S>() -> SameIndent<S> { SameIndent(std::marker::PhantomData) }
+
+impl<'r, 'i, S> Rule<'r, Stateful<&'i str, S>> for SameIndent<S>
+    where
+        S: AsRef<Indents>,
+{
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: Stateful<&'i str, S>) -> Ruled<Stateful<&'i str, S>, Self::Mat, Self::Exp> {
+        let text = input.input();
+        let measured = input.with(|s| {
+            let indents = s.as_ref();
+            indents.measure(text).map(|m| (m, indents.current()))
+        });
+
+        match measured {
+            Ok((Some((width, line, end)), current)) if width == current => {
+                Match(&text[line..end], input.advance(&text[end..]))
+            }
+            _ => Expected(Failed::Indent),
+        }
+    }
+}
+
+impl_ops!(SameIndent<S>);
+
+/// Checks that the next non-blank line is indented less than the current level
+/// or the input ends. Consumes nothing.
+///
+/// Fails with [`Failed::Indent`] otherwise.
+///
+/// [`Failed::Indent`]: ../enum.Failed.html#variant.Indent
+#[derive(Debug)]
+pub struct Dedent<S>(std::marker::PhantomData<S>);
+
+impl<S> Clone for Dedent<S> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<S> Copy for Dedent<S> {}
+
+/// [`Dedent`] constructor. See [`Dedent`] and [`Indents`] for details.
+///
+/// [`Dedent`]: ./struct.Dedent.html
+/// [`Indents`]: ./struct.Indents.html
+pub fn dedent<S>() -> Dedent<S> { Dedent(std::marker::PhantomData) }
+
+impl<'r, 'i, S> Rule<'r, Stateful<&'i str, S>> for Dedent<S>
+    where
+        S: AsRef<Indents>,
+{
+    type Mat = ();
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: Stateful<&'i str, S>) -> Ruled<Stateful<&'i str, S>, Self::Mat, Self::Exp> {
+        let measured = input.with(|s| {
+            let indents = s.as_ref();
+            indents.measure(input.input()).map(|m| (m, indents.current()))
+        });
+
+        match measured {
+            Ok((None, _)) => Match((), input),
+            Ok((Some((width, _, _)), current)) if width < current => Match((), input),
+            _ => Expected(Failed::Indent),
+        }
+    }
+}
+
+impl_ops!(Dedent<S>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        compound::{lift, take_while1},
+        State,
+    };
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Node<'i>(&'i str, Vec<Node<'i>>);
+
+    type Input<'i> = Stateful<&'i str, Indents>;
+
+    /// Parses lines of names, where deeper indented lines are children of the previous one.
+    struct Block;
+
+    static CHILDREN: Indented<Block> = Indented(Block);
+
+    impl<'r, 'i> Rule<'r, Input<'i>> for Block {
+        type Mat = Vec<Node<'i>>;
+        type Exp = Failed<'static>;
+
+        fn rule(&'r self, mut input: Input<'i>) -> Ruled<Input<'i>, Self::Mat, Self::Exp> {
+            let nl = char_set("\r\n");
+            let line = (same_indent(), lift(take_while1(char::is_alphabetic)), lift(nl.opt()));
+            let mut nodes = Vec::new();
+
+            while let Match((_, name, _), rest) = line.rule(input.clone()) {
+                input = rest;
+
+                let children = match CHILDREN.rule(input.clone()) {
+                    Match(children, rest) => {
+                        input = rest;
+                        children
+                    }
+                    Expected(_) => vec![],
+                };
+
+                nodes.push(Node(name, children));
+            }
+
+            if nodes.is_empty() {
+                Expected(Failed::Indent)
+            } else {
+                Match(nodes, input)
+            }
+        }
+    }
+
+    fn parse(text: &str, indents: Indents) -> Option<(Vec<Node<'_>>, &str)> {
+        let state = State::new(indents);
+        match Block.rule(state.input(text)) {
+            Match(nodes, rest) => Some((nodes, rest.input())),
+            Expected(_) => None,
+        }
+    }
+
+    #[test]
+    fn indented() {
+        let text = "a\n  b\n\n  c\n    d\r\n  \n  e\nf";
+        let (nodes, rest) = parse(text, Indents::new()).unwrap();
+
+        assert_eq!(nodes, vec![
+            Node("a", vec![
+                Node("b", vec![]),
+                Node("c", vec![Node("d", vec![])]),
+                Node("e", vec![]),
+            ]),
+            Node("f", vec![]),
+        ]);
+        assert_eq!(rest, "");
+
+        // Inconsistent dedent stops the block
+        let (nodes, rest) = parse("a\n    b\n  c", Indents::new()).unwrap();
+        assert_eq!(nodes, vec![Node("a", vec![Node("b", vec![])])]);
+        assert_eq!(rest, "  c");
+
+        assert_eq!(parse("  a", Indents::new()), None);
+    }
+
+    #[test]
+    fn tabs() {
+        let (nodes, _) = parse("a\n\tb\n        c", Indents::new()).unwrap();
+        assert_eq!(nodes, vec![Node("a", vec![Node("b", vec![]), Node("c", vec![])])]);
+
+        let (nodes, _) = parse("a\n\tb\n    c", Indents::new().tabs(Tabs::Width(4))).unwrap();
+        assert_eq!(nodes, vec![Node("a", vec![Node("b", vec![]), Node("c", vec![])])]);
+
+        let (nodes, _) = parse("a\n\tb\n c", Indents::new().tabs(Tabs::Width(0))).unwrap();
+        assert_eq!(nodes, vec![Node("a", vec![Node("b", vec![]), Node("c", vec![])])]);
+
+        let (nodes, rest) = parse("a\n\tb", Indents::new().tabs(Tabs::Forbid)).unwrap();
+        assert_eq!(nodes, vec![Node("a", vec![])]);
+        assert_eq!(rest, "\tb");
+    }
+
+    #[test]
+    fn dedent() {
+        let state = State::new(Indents::new());
+        let r = super::indented((same_indent(), lift(char_range('a'..='z')), lift('\n'), super::dedent()));
+
+        assert!(r.rule(state.input("  a\nb")).is_match());
+        assert!(r.rule(state.input("  a\n")).is_match());
+        assert_eq!(r.rule(state.input("  a\n  b")).exp(), Some(Failed::Indent));
+        assert_eq!(r.rule(state.input("a")).exp(), Some(Failed::Indent));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::prelude::*;
+
+/// Keyword parser.
+///
+/// Matches the literal only if it isn't followed by an identifier char,
+/// so the keyword `if` doesn't match the start of `iffy`.
+/// By default identifier chars have the Unicode `XID_Continue` property,
+/// use [`ident`] to set another predicate.
+/// Fails with [`Failed::Keyword`] otherwise.
+///
+/// [`ident`]: ./struct.Keyword.html#method.ident
+/// [`Failed::Keyword`]: ../enum.Failed.html#variant.Keyword
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::keyword};
+/// let kw = keyword("if");
+/// assert_eq!(kw.rule("if x"), Match("if", " x"));
+/// assert_eq!(kw.rule("if(x)"), Match("if", "(x)"));
+/// assert_eq!(kw.rule("iffy"), Expected(Failed::Keyword("if")));
+///
+/// let kw = keyword("if").ident(|c: char| c.is_ascii_alphanumeric() || c == '-');
+/// assert_eq!(kw.rule("if-else"), Expected(Failed::Keyword("if")));
+/// assert_eq!(kw.rule("ifé"), Match("if", "é"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Keyword<'k, F> {
+    word: &'k str,
+    ident: F,
+}
+
+/// [`Keyword`] constructor. See [`Keyword`] for details.
+///
+/// [`Keyword`]: ./struct.Keyword.html
+pub fn keyword(word: &str) -> Keyword<'_, fn(char) -> bool> {
+    Keyword {
+        word,
+        ident: unicode_ident::is_xid_continue,
+    }
+}
+
+impl<'k, F> Keyword<'k, F> {
+    /// Sets the predicate of identifier chars.
+    pub fn ident<G>(self, ident: G) -> Keyword<'k, G>
+        where
+            G: Fn(char) -> bool,
+    {
+        Keyword {
+            word: self.word,
+            ident,
+        }
+    }
+}
+
+impl<'r, 'i, 'k: 'r, F> Rule<'r, &'i str> for Keyword<'k, F>
+    where
+        F: Fn(char) -> bool,
+{
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        if input.starts_with(self.word) {
+            let (mat, rest) = input.split_at(self.word.len());
+
+            match rest.chars().next() {
+                Some(c) if (self.ident)(c) => {}
+                _ => return Match(mat, rest),
+            }
+        }
+
+        Expected(Failed::Keyword(self.word))
+    }
+}
+
+impl_ops!(Keyword<'k, F>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyword() {
+        let r = super::keyword("fn");
+        assert_eq!(r.rule("fn"), Match("fn", ""));
+        assert_eq!(r.rule("fn main"), Match("fn", " main"));
+        assert_eq!(r.rule("fn_main"), Expected(Failed::Keyword("fn")));
+        assert_eq!(r.rule("fnк"), Expected(Failed::Keyword("fn")));
+        assert_eq!(r.rule("f"), Expected(Failed::Keyword("fn")));
+
+        let r = super::keyword("fn").ident(char::is_alphabetic);
+        assert_eq!(r.rule("fn_main"), Match("fn", "_main"));
+        assert_eq!(r.rule("fnк"), Expected(Failed::Keyword("fn")));
+    }
+}
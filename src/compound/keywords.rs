@@ -0,0 +1,155 @@
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    edges: Vec<(u8, usize)>,
+    value: Option<usize>,
+}
+
+/// Keyword table parser.
+///
+/// The keywords are compiled into a trie, so the input is matched in one pass
+/// regardless of the table size. Returns the value associated with the longest
+/// matched keyword. Fails with [`Failed::Keywords`] containing all keywords of the table.
+/// If a keyword repeats, the last value is used.
+///
+/// [`Failed::Keywords`]: ../enum.Failed.html#variant.Keywords
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::keywords};
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum Op { Lt, Le, Shl, ShlAssign }
+///
+/// let op = keywords([("<", Op::Lt), ("<=", Op::Le), ("<<", Op::Shl), ("<<=", Op::ShlAssign)]);
+/// assert_eq!(op.rule("<<=1"), Match(Op::ShlAssign, "1"));
+/// assert_eq!(op.rule("<1"), Match(Op::Lt, "1"));
+/// assert_eq!(op.rule("=1"), Expected(Failed::Keywords(&["<", "<=", "<<", "<<="])));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Keywords<'k, V> {
+    nodes: Vec<Node>,
+    words: Vec<&'k str>,
+    values: Vec<V>,
+}
+
+/// [`Keywords`] constructor. See [`Keywords`] for details.
+///
+/// [`Keywords`]: ./struct.Keywords.html
+pub fn keywords<'k, V, K>(table: K) -> Keywords<'k, V>
+    where
+        K: IntoIterator<Item=(&'k str, V)>,
+{
+    let mut kw = Keywords {
+        nodes: vec![Node::default()],
+        words: Vec::new(),
+        values: Vec::new(),
+    };
+
+    for (word, value) in table {
+        kw.insert(word, value);
+    }
+
+    kw
+}
+
+impl<'k, V> Keywords<'k, V> {
+    fn insert(&mut self, word: &'k str, value: V) {
+        let mut node = 0;
+
+        for &b in word.as_bytes() {
+            node = match self.nodes[node].edges.binary_search_by_key(&b, |&(e, _)| e) {
+                Ok(i) => self.nodes[node].edges[i].1,
+                Err(i) => {
+                    let next = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].edges.insert(i, (b, next));
+                    next
+                }
+            };
+        }
+
+        match self.nodes[node].value {
+            Some(v) => self.values[v] = value,
+            None => {
+                self.nodes[node].value = Some(self.values.len());
+                self.words.push(word);
+                self.values.push(value);
+            }
+        }
+    }
+
+    /// Returns the keywords of the table.
+    pub fn words(&self) -> &[&'k str] { &self.words }
+
+    /// Returns the index of the value and the length of the longest keyword
+    /// at the start of the input.
+    fn longest(&self, input: &[u8]) -> Option<(usize, usize)> {
+        let mut node = 0;
+        let mut found = self.nodes[0].value.map(|v| (v, 0));
+
+        for (len, b) in input.iter().enumerate() {
+            let edges = &self.nodes[node].edges;
+
+            node = match edges.binary_search_by_key(b, |&(e, _)| e) {
+                Ok(i) => edges[i].1,
+                Err(_) => break,
+            };
+
+            if let Some(v) = self.nodes[node].value {
+                found = Some((v, len + 1));
+            }
+        }
+
+        found
+    }
+}
+
+impl<'r, 'i, 'k: 'r, V> Rule<'r, &'i str> for Keywords<'k, V>
+    where
+        V: Clone,
+{
+    type Mat = V;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match self.longest(input.as_bytes()) {
+            Some((v, len)) => Match(self.values[v].clone(), &input[len..]),
+            None => Expected(Failed::Keywords(&self.words)),
+        }
+    }
+}
+
+impl_ops!(Keywords<'k, V>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        let r = super::keywords(vec![("if", 1), ("in", 2), ("int", 3), ("else", 4)]);
+        assert_eq!(r.rule("if x"), Match(1, " x"));
+        assert_eq!(r.rule("in"), Match(2, ""));
+        assert_eq!(r.rule("integer"), Match(3, "eger"));
+        assert_eq!(r.rule("els"), Expected(Failed::Keywords(&["if", "in", "int", "else"])));
+        assert_eq!(r.rule(""), Expected(Failed::Keywords(r.words())));
+    }
+
+    #[test]
+    fn unicode() {
+        let r = super::keywords([("да", true), ("дан", false), ("нет", false)]);
+        assert_eq!(r.rule("данные"), Match(false, "ные"));
+        assert_eq!(r.rule("да!"), Match(true, "!"));
+        assert!(r.rule("д").is_expected());
+    }
+
+    #[test]
+    fn repeated() {
+        let r = super::keywords([("a", 1), ("a", 2), ("", 0)]);
+        assert_eq!(r.rule("a"), Match(2, ""));
+        assert_eq!(r.rule("b"), Match(0, "b"));
+        assert_eq!(r.words(), ["a", ""]);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    prelude::*,
+    Consumed,
+};
+
+/// Tries every rule of the tuple and returns the match consuming the most input.
+///
+/// Unlike [`Or`], which is an ordered choice, the result doesn't depend on
+/// the order of alternatives, except the tie, which is won by the first one.
+/// If no rule matches, fails with the error of the last one.
+/// Implemented for tuples of 2 to 7 rules.
+///
+/// [`Or`]: ./struct.Or.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::longest};
+/// let op = longest(("<", "<=", "<<="));
+/// assert_eq!(op.rule("<<=1"), Match("<<=", "1"));
+/// assert_eq!(op.rule("<=1"), Match("<=", "1"));
+/// assert_eq!(op.rule("<1"), Match("<", "1"));
+/// assert_eq!(op.rule("=1"), Expected(Failed::Str("<<=")));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Longest<T>(pub T);
+
+/// [`Longest`] constructor. See [`Longest`] for details.
+///
+/// [`Longest`]: ./struct.Longest.html
+pub fn longest<T>(rules: T) -> Longest<T> { Longest(rules) }
+
+impl_ops!(Longest<L>);
+
+/// Tries every rule of the slice and returns the match consuming the most input.
+///
+/// The tie is won by the first rule. If no rule matches, fails with `()`
+/// like [`OneOf`].
+///
+/// [`OneOf`]: ./struct.OneOf.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::longest_of};
+/// let word = longest_of(&["hi", "high"]);
+/// assert_eq!(word.rule("higher"), Match("high", "er"));
+/// assert_eq!(word.rule("hi!"), Match("hi", "!"));
+/// assert_eq!(word.rule("lo"), Expected(()));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct LongestOf<'a, A>(pub &'a [A]);
+
+/// [`LongestOf`] constructor. See [`LongestOf`] for details.
+///
+/// [`LongestOf`]: ./struct.LongestOf.html
+pub fn longest_of<A>(rules: &[A]) -> LongestOf<'_, A> { LongestOf(rules) }
+
+impl<'r, 'a, I, A> Rule<'r, I> for LongestOf<'a, A>
+    where
+        A: Rule<'r, I>,
+        I: Consumed,
+{
+    type Mat = A::Mat;
+    type Exp = ();
+
+    fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+        let mut best: Option<(A::Mat, I)> = None;
+
+        for rule in self.0 {
+            if let Match(m, i) = rule.rule(input.clone()) {
+                match &best {
+                    Some((_, b)) if i.rest_len() >= b.rest_len() => {}
+                    _ => best = Some((m, i)),
+                }
+            }
+        }
+
+        match best {
+            Some((m, i)) => Match(m, i),
+            None => Expected(()),
+        }
+    }
+}
+
+impl_ops!(LongestOf<'a, A>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest() {
+        let r = super::longest(("a", "ab", "abc"));
+        assert_eq!(r.rule("abcd"), Match("abc", "d"));
+        assert_eq!(r.rule("abd"), Match("ab", "d"));
+        assert_eq!(r.rule("x"), Expected(Failed::Str("abc")));
+
+        // The tie is won by the first rule
+        let r = super::longest((rul('a').map(|_| 1), rul('a').map(|_| 2), rul('b').map(|_| 3)));
+        assert_eq!(r.rule("a"), Match(1, ""));
+        assert_eq!(r.rule("b"), Match(3, ""));
+    }
+
+    #[test]
+    fn longest_of() {
+        let r = super::longest_of(&["<", "<=", "<<", "<<="]);
+        assert_eq!(r.rule("<<=x"), Match("<<=", "x"));
+        assert_eq!(r.rule("<<x"), Match("<<", "x"));
+        assert_eq!(r.rule("<x"), Match("<", "x"));
+        assert_eq!(r.rule("x"), Expected(()));
+
+        let rules = [vec![1], vec![1, 2]];
+        let r = super::longest_of(&rules);
+        assert_eq!(r.rule([1, 2, 3].as_ref()), Match([1, 2].as_ref(), [3].as_ref()));
+    }
+}
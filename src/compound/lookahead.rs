@@ -0,0 +1,111 @@
+use crate::{
+    prelude::*,
+    Consumed,
+};
+
+/// Positive lookahead.
+///
+/// Matches the rule and returns its match, but doesn't consume the input.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::peek};
+/// let r = peek("let");
+/// assert_eq!(r.rule("let x"), Match("let", "let x"));
+/// assert_eq!(r.rule("var x"), Expected(Failed::Str("let")));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Peek<R>(pub R);
+
+/// [`Peek`] constructor. See [`Peek`] for details.
+///
+/// [`Peek`]: ./struct.Peek.html
+pub fn peek<R>(rule: R) -> Peek<R> { Peek(rule) }
+
+impl<'r, I, R> Rule<'r, I> for Peek<R>
+    where
+        R: Rule<'r, I>,
+        I: Clone,
+{
+    type Mat = R::Mat;
+    type Exp = R::Exp;
+
+    fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+        self.0.rule(input.clone())
+            .and_then(|m, _| Match(m, input))
+    }
+}
+
+impl_ops!(Peek<R>);
+
+/// Negative lookahead.
+///
+/// Succeeds with `()` if the rule fails and consumes nothing.
+/// If the rule matches, fails with [`Failed::Unexpected`] containing
+/// the length of the unwanted match.
+/// Unlike [`Not`], the result types don't depend on the rule.
+///
+/// [`Failed::Unexpected`]: ../enum.Failed.html#variant.Unexpected
+/// [`Not`]: ./struct.Not.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::not_followed_by};
+/// let r = ('1', not_followed_by(char_range('0'..='9')));
+/// assert_eq!(r.rule("1+"), Match(("1", ()), "+"));
+/// assert_eq!(r.rule("12"), Expected(Failed::Unexpected(1)));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct NotFollowedBy<R>(pub R);
+
+/// [`NotFollowedBy`] constructor. See [`NotFollowedBy`] for details.
+///
+/// [`NotFollowedBy`]: ./struct.NotFollowedBy.html
+pub fn not_followed_by<R>(rule: R) -> NotFollowedBy<R> { NotFollowedBy(rule) }
+
+impl<'r, I, R> Rule<'r, I> for NotFollowedBy<R>
+    where
+        R: Rule<'r, I>,
+        I: Consumed,
+{
+    type Mat = ();
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+        match self.0.rule(input.clone()) {
+            Match(_, rest) => Expected(Failed::Unexpected(input.rest_len() - rest.rest_len())),
+            Expected(_) => Match((), input),
+        }
+    }
+}
+
+impl_ops!(NotFollowedBy<R>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::dec;
+
+    #[test]
+    fn peek() {
+        let r = super::peek(dec().skip_many1());
+        assert_eq!(r.rule("12a"), Match((), "12a"));
+        assert_eq!(r.rule("a"), Expected(Failed::Dec));
+
+        let r = (super::peek(vec![1]), vec![1, 2]);
+        assert_eq!(r.rule([1, 2, 3].as_ref()), Match(([1].as_ref(), [1, 2].as_ref()), [3].as_ref()));
+    }
+
+    #[test]
+    fn not_followed_by() {
+        let r = super::not_followed_by("*/");
+        assert_eq!(r.rule("*x"), Match((), "*x"));
+        assert_eq!(r.rule(""), Match((), ""));
+        assert_eq!(r.rule("*/x"), Expected(Failed::Unexpected(2)));
+
+        let r = super::not_followed_by(dec().skip_many());
+        assert_eq!(r.rule("x"), Expected(Failed::Unexpected(0)));
+    }
+}
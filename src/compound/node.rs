@@ -0,0 +1,82 @@
+use crate::{
+    prelude::*,
+    Children,
+    Consumed,
+    ParseTree,
+    Span,
+};
+
+/// Wraps the match of the rule into a named [`ParseTree`] node.
+///
+/// The nodes contained in the match of the rule become the children of the node,
+/// so nested `node` rules build a tree. Created with [`Rule::node`].
+///
+/// [`ParseTree`]: ../struct.ParseTree.html
+/// [`Rule::node`]: ../trait.Rule.html#method.node
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::prelude::*;
+/// let word = char_range('a'..='z').skip_many1().recognize().node("word");
+/// let pair = (&word, ' ', &word).node("pair");
+///
+/// let tree = pair.rule("ab cd!").mat().unwrap();
+/// assert_eq!(tree.text, "ab cd");
+/// assert_eq!(tree.to_sexp(), r#"(pair (word "ab") (word "cd"))"#);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Node<'n, R>(pub R, pub &'n str);
+
+impl<'r, 'i, 'n, R> Rule<'r, &'i str> for Node<'n, R>
+    where
+        R: Rule<'r, &'i str>,
+        R::Mat: Children<'n, 'i>,
+{
+    type Mat = ParseTree<'n, 'i>;
+    type Exp = R::Exp;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        self.0.rule(input)
+            .and_then(|m, rest| {
+                let mut children = Vec::new();
+                m.collect_into(&mut children);
+
+                Match(ParseTree {
+                    name: self.1,
+                    span: Span::new(input, rest),
+                    text: input.consumed(rest),
+                    children,
+                }, rest)
+            })
+    }
+}
+
+impl_ops!(Node<'n, R>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::{float, int, latin, space};
+
+    #[test]
+    fn node() {
+        let num = char_range('0'..='9').skip_many1().recognize().node("num");
+        let list = ('[', (&num, (',', &num).range::<Vec<_>, _>(..)).opt(), ']').node("list");
+
+        let tree = list.rule("[1,23]").mat().unwrap();
+        assert_eq!(tree.name, "list");
+        assert_eq!(tree.children.iter().map(|n| n.text).collect::<Vec<_>>(), ["1", "23"]);
+        assert_eq!(tree.children[1].span.range("[1,23]"), 3..5);
+        assert_eq!(list.rule("[]").mat().unwrap().children, []);
+        assert_eq!(list.rule("[1"), Expected(Failed::Char(']')));
+
+        let name = String::from("letter");
+        let r = latin().node(&name);
+        assert_eq!(r.rule("ab").mat().unwrap().name, "letter");
+
+        let r = (int::<i64>().node("int"), space(), float::<f64>().node("float"), space().count()).node("nums");
+        let tree = r.rule("-1 2.5  ").mat().unwrap();
+        assert_eq!(tree.to_sexp(), r#"(nums (int "-1") (float "2.5"))"#);
+    }
+}
@@ -6,13 +6,13 @@ pub struct Not<A>(pub A);
 impl<'r, I, A> Rule<'r, I> for Not<A>
     where
         A: Rule<'r, I>,
-        I: Copy,
+        I: Clone,
 {
     type Mat = A::Exp;
     type Exp = A::Mat;
 
     fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
-        match self.0.rule(input) {
+        match self.0.rule(input.clone()) {
             Match(r, _) => Expected(r),
             Expected(e) => Match(e, input),
         }
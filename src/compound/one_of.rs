@@ -3,19 +3,19 @@ use crate::prelude::*;
 #[derive(Copy, Clone, Debug)]
 pub struct OneOf<'a, A>(pub &'a [A]);
 
-pub fn one_of<A>(rules: &[A]) -> OneOf<A> { OneOf(rules) }
+pub fn one_of<A>(rules: &[A]) -> OneOf<'_, A> { OneOf(rules) }
 
 impl<'r, 'a, I, A> Rule<'r, I> for OneOf<'a, A>
     where
         A: Rule<'r, I>,
-        I: Copy,
+        I: Clone,
 {
     type Mat = A::Mat;
     type Exp = ();
 
     fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
         for rule in self.0 {
-            if let Match(r, i) = rule.rule(input) {
+            if let Match(r, i) = rule.rule(input.clone()) {
                 return Match(r, i);
             }
         }
@@ -6,13 +6,13 @@ pub struct Opt<R>(pub R);
 impl<'r, I, R> Rule<'r, I> for Opt<R>
     where
         R: Rule<'r, I>,
-        I: Copy,
+        I: Clone,
 {
     type Mat = Option<R::Mat>;
     type Exp = R::Exp;
 
     fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
-        match self.0.rule(input) {
+        match self.0.rule(input.clone()) {
             Match(r, i) => Match(Some(r), i),
             Expected(_) => Match(None, input),
         }
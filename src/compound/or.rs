@@ -7,14 +7,13 @@ impl<'r, I, A, B> Rule<'r, I> for Or<A, B>
     where
         A: Rule<'r, I>,
         B: Rule<'r, I, Mat=A::Mat, Exp=A::Exp>,
-        I: Copy,
+        I: Clone,
 {
     type Mat = B::Mat;
     type Exp = A::Exp;
 
     fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
-        self.0.rule(input)
-            .map(|l| l.into())
+        self.0.rule(input.clone())
             .or_else(|_| self.1.rule(input))
     }
 }
@@ -6,14 +6,14 @@ pub struct OrDefault<R>(pub R);
 impl<'r, I, R> Rule<'r, I> for OrDefault<R>
     where
         R: Rule<'r, I>,
-        I: Copy,
+        I: Clone,
         R::Mat: Default,
 {
     type Mat = R::Mat;
     type Exp = R::Exp;
 
     fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
-        match self.0.rule(input) {
+        match self.0.rule(input.clone()) {
             o @ Ruled::Match(_, _) => o,
             Ruled::Expected(_) => Ruled::Match(Default::default(), input),
         }
@@ -0,0 +1,41 @@
+use crate::{
+    prelude::*,
+    Parse,
+};
+
+/// Parses a value of the type implementing [`Parse`].
+///
+/// [`Parse`]: ../trait.Parse.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::parse};
+/// let pair = (parse::<u8>(), ',', parse::<bool>());
+/// assert_eq!(pair.rule("7,true"), Match((7, ",", true), ""));
+/// ```
+#[derive(Debug)]
+pub struct Parsed<P>(std::marker::PhantomData<P>);
+
+impl<P> Clone for Parsed<P> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<P> Copy for Parsed<P> {}
+
+/// [`Parsed`] constructor. See [`Parsed`] for details.
+///
+/// [`Parsed`]: ./struct.Parsed.html
+pub fn parse<P>() -> Parsed<P> { Parsed(std::marker::PhantomData) }
+
+impl<'r, 'i, P> Rule<'r, &'i str> for Parsed<P>
+    where
+        P: Parse<'i>,
+{
+    type Mat = P;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> { P::parse(input) }
+}
+
+impl_ops!(Parsed<P>);
@@ -0,0 +1,62 @@
+/// Matches each rule of the tuple exactly once in any order.
+///
+/// Returns the matches in the order of the tuple.
+/// At each position the rules are tried in the order of the tuple, and the first one
+/// that consumes the input is taken. Rules that can match the empty input,
+/// like [`Opt`], are applied last to the rest of the input.
+/// Fails with the error of the first rule that didn't match.
+/// Implemented for tuples of 2 to 7 rules.
+///
+/// [`Opt`]: ./struct.Opt.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::permutation};
+/// let attrs = permutation(("a;", "b;", rul("c;").opt()));
+/// assert_eq!(attrs.rule("b;a;"), Match(("a;", "b;", None), ""));
+/// assert_eq!(attrs.rule("c;b;a;!"), Match(("a;", "b;", Some("c;")), "!"));
+/// assert_eq!(attrs.rule("a;a;"), Expected(Failed::Str("b;")));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Permutation<T>(pub T);
+
+/// [`Permutation`] constructor. See [`Permutation`] for details.
+///
+/// [`Permutation`]: ./struct.Permutation.html
+pub fn permutation<T>(rules: T) -> Permutation<T> { Permutation(rules) }
+
+impl_ops!(Permutation<P>);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn permutation() {
+        let r = super::permutation(('a', 'b', 'c'));
+        assert_eq!(r.rule("abc"), Match(("a", "b", "c"), ""));
+        assert_eq!(r.rule("cab!"), Match(("a", "b", "c"), "!"));
+        assert_eq!(r.rule("bca"), Match(("a", "b", "c"), ""));
+        assert_eq!(r.rule("ab"), Expected(Failed::Char('c')));
+        assert_eq!(r.rule("aab"), Expected(Failed::Char('b')));
+    }
+
+    #[test]
+    fn optional() {
+        let r = super::permutation((rul('x').opt(), rul('y'), rul('z').opt()));
+        assert_eq!(r.rule("y"), Match((None, "y", None), ""));
+        assert_eq!(r.rule("zy"), Match((None, "y", Some("z")), ""));
+        assert_eq!(r.rule("yxz"), Match((Some("x"), "y", Some("z")), ""));
+        assert_eq!(r.rule("x"), Expected(Failed::Char('y')));
+
+        // Each rule is applied once
+        assert_eq!(r.rule("yxx"), Match((Some("x"), "y", None), "x"));
+    }
+
+    #[test]
+    fn slice() {
+        let r = super::permutation((vec![1, 2], vec![3]));
+        assert_eq!(r.rule([3, 1, 2].as_ref()), Match(([1, 2].as_ref(), [3].as_ref()), [].as_ref()));
+    }
+}
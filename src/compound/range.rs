@@ -57,7 +57,7 @@ impl<R, C> Copy for Range<R, C>
 impl<'r, I, R, C> Rule<'r, I> for Range<R, C>
     where
         R: Rule<'r, I>,
-        I: Copy,
+        I: Clone,
         C: Concat<C, R::Mat>,
 {
     type Mat = C;
@@ -72,7 +72,7 @@ impl<'r, I, R, C> Rule<'r, I> for Range<R, C>
                 break Match(res, input);
             }
 
-            match self.rule.rule(input) {
+            match self.rule.rule(input.clone()) {
                 Match(r, i) => {
                     count += 1;
                     input = i;
@@ -93,6 +93,7 @@ impl<'r, I, R, C> Rule<'r, I> for Range<R, C>
 impl_or!(Range<R, C>);
 impl_shifts!(Range<R, C>);
 impl_not!(Range<R, C>);
+impl_sub!(Range<R, C>);
 
 impl<R, T> std::ops::BitAnd<T> for Range<R, &'static str> {
     type Output = super::Cat<Range<R, &'static str>, T, &'static str>;
@@ -109,6 +110,7 @@ impl<R, T> std::ops::Add<T> for Range<R, String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Count;
 
     #[test]
     fn range() {
@@ -172,4 +174,26 @@ mod tests {
         assert_eq!(r.rule("aa"), Match("aa".to_owned(), ""));
         assert_eq!(r.rule("aaa"), Match("aaa".to_owned(), ""));
     }
+
+    #[test]
+    fn skip_many() {
+        let r = "a".skip_many();
+        assert_eq!(r.rule("~"), Match((), "~"));
+        assert_eq!(r.rule("aa~"), Match((), "~"));
+
+        let r = "a".skip_many1();
+        assert_eq!(r.rule("~"), Expected(Failed::Str("a")));
+        assert_eq!(r.rule("aa~"), Match((), "~"));
+    }
+
+    #[test]
+    fn count() {
+        let r = "a".count();
+        assert_eq!(r.rule("~"), Match(0, "~"));
+        assert_eq!(r.rule("aaa~"), Match(3, "~"));
+
+        let r = "a".range::<Count, _>(2..=3);
+        assert_eq!(r.rule("a~"), Expected(Failed::Str("a")));
+        assert_eq!(r.rule("aaaa~"), Match(Count(3), "a~"));
+    }
 }
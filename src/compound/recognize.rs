@@ -0,0 +1,64 @@
+use crate::{
+    prelude::*,
+    Consumed,
+};
+
+/// Returns the part of the input consumed by the rule instead of its match.
+///
+/// The rule can be anything: tuples, mapped rules, optional rules and so on.
+/// The consumed slice is computed from the input before and after the rule,
+/// so it doesn't copy and doesn't require the rule to match adjacent slices.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::{alphanumeric, latin}};
+/// let ident = (latin(), alphanumeric().skip_many()).recognize();
+/// assert_eq!(ident.rule("x12y;"), Match("x12y", ";"));
+/// assert_eq!(ident.rule("1"), Expected(Failed::Latin));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Recognize<R>(pub R);
+
+impl<'r, I, R> Rule<'r, I> for Recognize<R>
+    where
+        R: Rule<'r, I>,
+        I: Consumed,
+{
+    type Mat = I;
+    type Exp = R::Exp;
+
+    fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+        self.0.rule(input.clone())
+            .and_then(|_, rest| Match(input.consumed(rest.clone()), rest))
+    }
+}
+
+impl_ops!(Recognize<R>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::{dec, latin};
+
+    #[test]
+    fn recognize() {
+        let r = (latin(), (latin(), dec()).range::<Vec<_>, _>(..)).recognize();
+        assert_eq!(r.rule("ab1c2!"), Match("ab1c2", "!"));
+        assert_eq!(r.rule("a"), Match("a", ""));
+        assert_eq!(r.rule("1"), Expected(Failed::Latin));
+
+        let r = dec().map(|d| d.len()).recognize();
+        assert_eq!(r.rule("5x"), Match("5", "x"));
+
+        let r = 'a'.opt().recognize();
+        assert_eq!(r.rule("b"), Match("", "b"));
+    }
+
+    #[test]
+    fn slice() {
+        let r = (vec![1], vec![2].opt(), vec![3]).recognize();
+        assert_eq!(r.rule([1, 3, 4].as_ref()), Match([1, 3].as_ref(), [4].as_ref()));
+        assert_eq!(r.rule([1, 2, 3].as_ref()), Match([1, 2, 3].as_ref(), [].as_ref()));
+    }
+}
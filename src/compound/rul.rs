@@ -1,7 +1,7 @@
 use crate::{
     prelude::*,
     IntoRule,
-    compound::{Cat, Or, Fst, Snd, Not},
+    compound::{Cat, Or, Fst, Snd, Not, Except},
 };
 
 /// The wrapper to provide useful features of [rules].
@@ -102,3 +102,9 @@ impl<R> std::ops::Not for Rul<R> {
 
     fn not(self) -> Self::Output { Not(self.0) }
 }
+
+impl<R, T> std::ops::Sub<T> for Rul<R> {
+    type Output = Except<R, T>;
+
+    fn sub(self, rhs: T) -> Self::Output { Except(self.0, rhs) }
+}
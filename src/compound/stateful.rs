@@ -0,0 +1,246 @@
+use crate::{
+    prelude::*,
+    Stateful,
+};
+
+/// Applies a stateless rule to the inner input of [`Stateful`].
+///
+/// The state type `S` is a part of the rule type,
+/// so it's inferred from the input the rule is applied to.
+///
+/// [`Stateful`]: ../struct.Stateful.html
+#[derive(Debug)]
+pub struct Lift<R, S> {
+    rule: R,
+    phantom: std::marker::PhantomData<S>,
+}
+
+/// [`Lift`] constructor. See [`Lift`] for details.
+///
+/// [`Lift`]: ./struct.Lift.html
+pub fn lift<R, S>(rule: R) -> Lift<R, S> {
+    Lift {
+        rule,
+        phantom: std::marker::PhantomData,
+    }
+}
+
+impl<R, S> Clone for Lift<R, S>
+    where
+        R: Clone,
+{
+    fn clone(&self) -> Self { lift(self.rule.clone()) }
+}
+
+impl<R, S> Copy for Lift<R, S>
+    where
+        R: Copy,
+{}
+
+impl<'r, I, S, R> Rule<'r, Stateful<I, S>> for Lift<R, S>
+    where
+        R: Rule<'r, I>,
+        I: Clone,
+{
+    type Mat = R::Mat;
+    type Exp = R::Exp;
+
+    fn rule(&'r self, input: Stateful<I, S>) -> Ruled<Stateful<I, S>, Self::Mat, Self::Exp> {
+        self.rule.rule(input.input())
+            .and_then(|m, rest| Match(m, input.advance(rest)))
+    }
+}
+
+impl_ops!(Lift<R, S>);
+
+/// Modifies the [`State`] with the match of the rule.
+///
+/// The modification is undone when the parsing backtracks before this rule.
+///
+/// [`State`]: ../struct.State.html
+#[derive(Copy, Clone, Debug)]
+pub struct Update<R, F>(pub R, pub F);
+
+impl<'r, I, S, R, F> Rule<'r, Stateful<I, S>> for Update<R, F>
+    where
+        R: Rule<'r, Stateful<I, S>>,
+        F: Fn(&mut S, &R::Mat),
+        S: Clone,
+{
+    type Mat = R::Mat;
+    type Exp = R::Exp;
+
+    fn rule(&'r self, input: Stateful<I, S>) -> Ruled<Stateful<I, S>, Self::Mat, Self::Exp> {
+        self.0.rule(input)
+            .and_then(|m, rest| {
+                let rest = rest.update(|s| (self.1)(s, &m));
+                Match(m, rest)
+            })
+    }
+}
+
+impl_ops!(Update<R, F>);
+
+/// Checks the match of the rule against the [`State`].
+///
+/// Fails with [`Failed::State`] if the predicate returns `false`.
+///
+/// [`State`]: ../struct.State.html
+/// [`Failed::State`]: ../enum.Failed.html#variant.State
+#[derive(Copy, Clone, Debug)]
+pub struct Check<R, F>(pub R, pub F);
+
+impl<'r, I, S, R, F> Rule<'r, Stateful<I, S>> for Check<R, F>
+    where
+        R: Rule<'r, Stateful<I, S>>,
+        R::Exp: Into<Failed<'r>>,
+        F: Fn(&S, &R::Mat) -> bool,
+{
+    type Mat = R::Mat;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: Stateful<I, S>) -> Ruled<Stateful<I, S>, Self::Mat, Self::Exp> {
+        self.0.rule(input)
+            .map_exp(|e| e.into())
+            .and_then(|m, rest| if rest.with(|s| (self.1)(s, &m)) {
+                Match(m, rest)
+            } else {
+                Expected(Failed::State)
+            })
+    }
+}
+
+impl_ops!(Check<R, F>);
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+    use crate::{
+        basic::{dec, int, latin, space},
+        State,
+    };
+
+    fn rest<I, S, M, E>(r: Ruled<Stateful<I, S>, M, E>) -> Stateful<I, S> {
+        match r {
+            Match(_, rest) => rest,
+            Expected(_) => panic!("The rule is expected to match"),
+        }
+    }
+
+    #[test]
+    fn lift() {
+        let state = State::new(());
+        let r = super::lift((dec().skip_many1(), space(), int::<i32>(), latin()));
+
+        match r.rule(state.input("12 -5x!")) {
+            Match(m, rest) => {
+                assert_eq!(m, ((), " ", -5, "x"));
+                assert_eq!(rest.input(), "!");
+            }
+            Expected(_) => panic!("The rule is expected to match"),
+        }
+
+        let r = super::lift(vec![1]);
+        assert_eq!(rest(r.rule(state.input([1, 2].as_ref()))).input(), [2]);
+
+        let r = super::lift('a').range::<(), _>(..);
+        assert_eq!(rest(r.rule(state.input("aab"))).input(), "b");
+    }
+
+    #[test]
+    fn update() {
+        let state = State::new(0);
+        let a = super::lift('a').update(|n: &mut i32, _: &&str| *n += 1);
+        let b = super::lift('b');
+        let c = super::lift('c');
+
+        // The first branch increments the state, then fails
+        let r = (a, b).or((super::lift('a'), c));
+        assert_eq!(rest(r.rule(state.input("ac"))).with(|&n| n), 0);
+
+        let r = a.range::<(), _>(..);
+        assert_eq!(rest(r.rule(state.input("aaab"))).with(|&n| n), 3);
+
+        // The last iteration increments the state, then fails
+        let r = (a, b).range::<(), _>(..);
+        assert_eq!(rest(r.rule(state.input("abab"))).with(|&n| n), 2);
+        assert_eq!(rest(r.rule(state.input("abac"))).with(|&n| n), 1);
+
+        let r = (a, b).opt();
+        assert_eq!(rest(r.rule(state.input("ac"))).with(|&n| n), 0);
+    }
+
+    #[test]
+    fn update_longest() {
+        let state = State::new(0);
+        let r = crate::compound::longest((
+            super::lift("ab").update(|n: &mut i32, _: &&str| *n = 1),
+            super::lift("a").update(|n: &mut i32, _: &&str| *n = 2),
+        ));
+
+        // The shorter branch runs last, but the longer one keeps its state
+        let rest = rest(r.rule(state.input("ab")));
+        assert_eq!(rest.input(), "");
+        assert_eq!(rest.with(|&n| n), 1);
+    }
+
+    /// Counts its live copies and the peak number of them.
+    #[derive(Debug)]
+    struct Live(Rc<Cell<(usize, usize)>>);
+
+    impl Clone for Live {
+        fn clone(&self) -> Self {
+            let (live, peak) = self.0.get();
+            self.0.set((live + 1, peak.max(live + 1)));
+            Live(Rc::clone(&self.0))
+        }
+    }
+
+    impl Drop for Live {
+        fn drop(&mut self) {
+            let (live, peak) = self.0.get();
+            self.0.set((live - 1, peak));
+        }
+    }
+
+    #[test]
+    fn update_frees() {
+        let counter = Rc::new(Cell::new((1, 1)));
+        let state = State::new(Live(Rc::clone(&counter)));
+        let r = super::lift('a').update(|_: &mut Live, _: &&str| {}).skip_many();
+
+        let text = "a".repeat(1000);
+        let rest = rest(r.rule(state.input(text.as_str())));
+        assert_eq!(rest.input(), "");
+
+        // Only the snapshots of the current and the previous inputs are alive
+        assert!(counter.get().1 <= 3);
+        drop(rest);
+        assert_eq!(counter.get().0, 1);
+    }
+
+    #[test]
+    fn default() {
+        let state = State::<i32>::default();
+        let r = super::lift('a').update(|n: &mut i32, _: &&str| *n += 1);
+        assert_eq!(rest(r.rule(state.input("a"))).with(|&n| n), 1);
+    }
+
+    #[test]
+    fn check() {
+        let state = State::new(2);
+        let r = super::lift('a')
+            .update(|n: &mut i32, _: &&str| *n -= 1)
+            .check(|&n, _| n >= 0)
+            .range::<(), _>(..);
+
+        let rest = rest(r.rule(state.input("aaaa")));
+        assert_eq!(rest.input(), "aa");
+        assert_eq!(rest.with(|&n| n), 0);
+
+        let r = super::lift('b').check(|&n: &i32, _: &&str| n > 2);
+        assert_eq!(r.rule(state.input("b")).exp(), Some(Failed::State));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::prelude::*;
+
+/// Consumes the input until the literal terminator.
+///
+/// The terminator is searched with a fast substring search, so it's much faster
+/// than [`Until`] for long inputs. Returns the slice of the input before the terminator
+/// without copying and leaves the terminator in the rest.
+/// Use [`inclusive`] to consume the terminator too.
+/// Fails with [`Failed::Str`] for `&str` input or [`Failed::Bytes`] for `&[u8]` input
+/// if there is no terminator.
+///
+/// [`Until`]: ./struct.Until.html
+/// [`inclusive`]: ./struct.TakeUntil.html#method.inclusive
+/// [`Failed::Str`]: ../enum.Failed.html#variant.Str
+/// [`Failed::Bytes`]: ../enum.Failed.html#variant.Bytes
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::take_until};
+/// let body = take_until("*/");
+/// assert_eq!(body.rule("a * b */ c"), Match("a * b ", "*/ c"));
+/// assert_eq!(body.inclusive().rule("a */ c"), Match("a */", " c"));
+/// assert_eq!(body.rule("a * b"), Expected(Failed::Str("*/")));
+///
+/// let line = take_until(b"\r\n").inclusive();
+/// assert_eq!(line.rule(b"GET /\r\nHost".as_ref()), Match(b"GET /\r\n".as_ref(), b"Host".as_ref()));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct TakeUntil<P> {
+    pat: P,
+    inclusive: bool,
+}
+
+/// [`TakeUntil`] constructor. See [`TakeUntil`] for details.
+///
+/// [`TakeUntil`]: ./struct.TakeUntil.html
+pub fn take_until<P>(pat: P) -> TakeUntil<P> { TakeUntil { pat, inclusive: false } }
+
+impl<P> TakeUntil<P> {
+    /// Consumes the terminator as a part of the match.
+    pub fn inclusive(self) -> Self { TakeUntil { inclusive: true, ..self } }
+
+    /// Returns the length of the match in bytes.
+    fn find(&self, input: &[u8]) -> Option<usize>
+        where
+            P: AsRef<[u8]>,
+    {
+        let pat = self.pat.as_ref();
+
+        memchr::memmem::find(input, pat)
+            .map(|pos| if self.inclusive { pos + pat.len() } else { pos })
+    }
+}
+
+impl<'r, 'i, 'p> Rule<'r, &'i str> for TakeUntil<&'p str>
+    where
+        'p: 'r,
+{
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match self.find(input.as_bytes()) {
+            Some(len) => input.split_at(len).into(),
+            None => Expected(Failed::Str(self.pat)),
+        }
+    }
+}
+
+impl<'r, 'i, P> Rule<'r, &'i [u8]> for TakeUntil<P>
+    where
+        P: AsRef<[u8]>,
+{
+    type Mat = &'i [u8];
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i [u8]) -> Ruled<&'i [u8], Self::Mat, Self::Exp> {
+        match self.find(input) {
+            Some(len) => input.split_at(len).into(),
+            None => Expected(Failed::Bytes(self.pat.as_ref())),
+        }
+    }
+}
+
+impl_ops!(TakeUntil<P>);
+
+/// Consumes the input until any byte of the set.
+///
+/// Sets of up to three bytes are searched with a vectorized search,
+/// larger ones with a lookup table. Returns the slice of the input before the found byte
+/// without copying and leaves it in the rest. Use [`inclusive`] to consume the byte too.
+/// For `&str` input bytes that aren't on a char boundary are skipped,
+/// so the set should contain ASCII bytes. Fails with [`Failed::AnyByte`]
+/// if there is no byte of the set.
+///
+/// [`inclusive`]: ./struct.TakeUntilAny.html#method.inclusive
+/// [`Failed::AnyByte`]: ../enum.Failed.html#variant.AnyByte
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::take_until_any};
+/// let chunk = take_until_any(b"\"\\");
+/// assert_eq!(chunk.rule(r#"abc\n""#), Match("abc", r#"\n""#));
+/// assert_eq!(chunk.rule("abc"), Expected(Failed::AnyByte(b"\"\\")));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct TakeUntilAny<P> {
+    set: P,
+    table: [bool; 256],
+    inclusive: bool,
+}
+
+/// [`TakeUntilAny`] constructor. See [`TakeUntilAny`] for details.
+///
+/// [`TakeUntilAny`]: ./struct.TakeUntilAny.html
+pub fn take_until_any<P>(set: P) -> TakeUntilAny<P>
+    where
+        P: AsRef<[u8]>,
+{
+    let mut table = [false; 256];
+
+    for &b in set.as_ref() {
+        table[b as usize] = true;
+    }
+
+    TakeUntilAny { set, table, inclusive: false }
+}
+
+impl<P> TakeUntilAny<P>
+    where
+        P: AsRef<[u8]>,
+{
+    /// Consumes the found byte as a part of the match.
+    pub fn inclusive(self) -> Self { TakeUntilAny { inclusive: true, ..self } }
+
+    /// Returns the position of the first byte of the set.
+    fn position(&self, input: &[u8]) -> Option<usize> {
+        match *self.set.as_ref() {
+            [] => None,
+            [a] => memchr::memchr(a, input),
+            [a, b] => memchr::memchr2(a, b, input),
+            [a, b, c] => memchr::memchr3(a, b, c, input),
+            _ => input.iter().position(|&b| self.table[b as usize]),
+        }
+    }
+
+    /// Returns the length of the match in bytes.
+    fn len(&self, pos: usize) -> usize { if self.inclusive { pos + 1 } else { pos } }
+}
+
+impl<'r, 'i, P> Rule<'r, &'i str> for TakeUntilAny<P>
+    where
+        P: AsRef<[u8]>,
+{
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        let mut start = 0;
+
+        while let Some(pos) = self.position(&input.as_bytes()[start..]) {
+            let pos = start + pos;
+            let len = self.len(pos);
+
+            if input.is_char_boundary(pos) && input.is_char_boundary(len) {
+                return input.split_at(len).into();
+            }
+
+            start = pos + 1;
+        }
+
+        Expected(Failed::AnyByte(self.set.as_ref()))
+    }
+}
+
+impl<'r, 'i, P> Rule<'r, &'i [u8]> for TakeUntilAny<P>
+    where
+        P: AsRef<[u8]>,
+{
+    type Mat = &'i [u8];
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i [u8]) -> Ruled<&'i [u8], Self::Mat, Self::Exp> {
+        match self.position(input) {
+            Some(pos) => input.split_at(self.len(pos)).into(),
+            None => Expected(Failed::AnyByte(self.set.as_ref())),
+        }
+    }
+}
+
+impl_ops!(TakeUntilAny<P>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_until() {
+        let r = super::take_until("-->");
+        assert_eq!(r.rule("a->b-->c"), Match("a->b", "-->c"));
+        assert_eq!(r.inclusive().rule("a->b-->c"), Match("a->b-->", "c"));
+        assert_eq!(r.rule("-->"), Match("", "-->"));
+        assert_eq!(r.rule("a--"), Expected(Failed::Str("-->")));
+
+        let r = super::take_until("ё");
+        assert_eq!(r.rule("еёж"), Match("е", "ёж"));
+
+        let r = super::take_until([0u8, 0]);
+        assert_eq!(r.rule([1, 0, 2, 0, 0, 3].as_ref()), Match([1, 0, 2].as_ref(), [0, 0, 3].as_ref()));
+        assert_eq!(r.rule([1, 0].as_ref()), Expected(Failed::Bytes(&[0, 0])));
+    }
+
+    #[test]
+    fn take_until_any() {
+        for set in ["\"", "\"\\", "\"\\\n", "\"\\\n\r"] {
+            let r = super::take_until_any(set);
+            assert_eq!(r.rule("ab\"c"), Match("ab", "\"c"));
+            assert_eq!(r.inclusive().rule("ab\"c"), Match("ab\"", "c"));
+            assert_eq!(r.rule(b"ab\"c".as_ref()), Match(b"ab".as_ref(), b"\"c".as_ref()));
+            assert_eq!(r.rule("abc"), Expected(Failed::AnyByte(set.as_bytes())));
+        }
+
+        // The second byte of 'ж' is 0xB6
+        let r = super::take_until_any([0xB6, b'!']);
+        assert_eq!(r.rule("ж!"), Match("ж", "!"));
+        assert_eq!(r.rule(&[0xB6, b'!'][..]), Match(&[][..], &[0xB6, b'!'][..]));
+
+        let r = super::take_until_any(b"");
+        assert!(r.rule("abc").is_expected());
+    }
+}
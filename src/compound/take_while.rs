@@ -0,0 +1,151 @@
+use crate::prelude::*;
+
+/// Consumes elements of the input while the predicate holds.
+///
+/// Returns the consumed slice of the input without copying.
+/// For `&str` input the predicate receives a `char`,
+/// for `&[T]` input it receives a `&T`.
+/// If fewer elements than required are consumed, fails with [`Failed::Predicate`].
+///
+/// [`Failed::Predicate`]: ../enum.Failed.html#variant.Predicate
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, compound::take_while1};
+/// let digits = take_while1(|c: char| c.is_ascii_digit());
+/// assert_eq!(digits.rule("123abc"), Match("123", "abc"));
+/// assert_eq!(digits.rule("abc"), Expected(Failed::Predicate));
+///
+/// let bytes = take_while1(u8::is_ascii_digit);
+/// assert_eq!(bytes.rule(b"12;".as_ref()), Match(b"12".as_ref(), b";".as_ref()));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct TakeWhile<F> {
+    pred: F,
+    from: usize,
+    to: Option<usize>,
+    till: bool,
+}
+
+/// Consumes any number of elements while the predicate holds.
+pub fn take_while<F>(pred: F) -> TakeWhile<F> { TakeWhile { pred, from: 0, to: None, till: false } }
+
+/// Consumes at least one element while the predicate holds.
+pub fn take_while1<F>(pred: F) -> TakeWhile<F> { TakeWhile { pred, from: 1, to: None, till: false } }
+
+/// Consumes from `m` to `n` elements inclusive while the predicate holds.
+pub fn take_while_m_n<F>(m: usize, n: usize, pred: F) -> TakeWhile<F> {
+    TakeWhile { pred, from: m, to: Some(n), till: false }
+}
+
+/// Consumes any number of elements until the predicate holds.
+pub fn take_till<F>(pred: F) -> TakeWhile<F> { TakeWhile { pred, from: 0, to: None, till: true } }
+
+impl<F> TakeWhile<F> {
+    /// Returns the length of the prefix satisfying the rule
+    /// or `None` if it's too short.
+    fn take<T, I>(&self, items: I, len: usize) -> Option<usize>
+        where
+            F: Fn(T) -> bool,
+            I: Iterator<Item=(usize, T)>,
+    {
+        let mut count = 0;
+        let mut end = len;
+
+        for (i, item) in items {
+            if self.to == Some(count) || (self.pred)(item) == self.till {
+                end = i;
+                break;
+            }
+
+            count += 1;
+        }
+
+        if count >= self.from {
+            Some(end)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'r, 'i, F> Rule<'r, &'i str> for TakeWhile<F>
+    where
+        F: Fn(char) -> bool,
+{
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match self.take(input.char_indices(), input.len()) {
+            Some(end) => input.split_at(end).into(),
+            None => Expected(Failed::Predicate),
+        }
+    }
+}
+
+impl<'r, 'i, T, F> Rule<'r, &'i [T]> for TakeWhile<F>
+    where
+        F: Fn(&T) -> bool,
+{
+    type Mat = &'i [T];
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i [T]) -> Ruled<&'i [T], Self::Mat, Self::Exp> {
+        match self.take(input.iter().enumerate(), input.len()) {
+            Some(end) => input.split_at(end).into(),
+            None => Expected(Failed::Predicate),
+        }
+    }
+}
+
+impl_ops!(TakeWhile<F>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_while() {
+        let r = super::take_while(|c: char| c.is_alphabetic());
+        assert_eq!(r.rule("шыa1"), Match("шыa", "1"));
+        assert_eq!(r.rule("1"), Match("", "1"));
+        assert_eq!(r.rule("abc"), Match("abc", ""));
+
+        let r = super::take_while1(|c: char| c.is_ascii_digit());
+        assert_eq!(r.rule("12a"), Match("12", "a"));
+        assert_eq!(r.rule("a"), Expected(Failed::Predicate));
+        assert_eq!(r.rule(""), Expected(Failed::Predicate));
+    }
+
+    #[test]
+    fn take_while_m_n() {
+        let r = super::take_while_m_n(2, 3, |c: char| c.is_ascii_hexdigit());
+        assert_eq!(r.rule("a"), Expected(Failed::Predicate));
+        assert_eq!(r.rule("ab"), Match("ab", ""));
+        assert_eq!(r.rule("abcd"), Match("abc", "d"));
+        assert_eq!(r.rule("ab!"), Match("ab", "!"));
+    }
+
+    #[test]
+    fn take_till() {
+        let r = super::take_till(|c: char| c == ';');
+        assert_eq!(r.rule("ab;c"), Match("ab", ";c"));
+        assert_eq!(r.rule(";"), Match("", ";"));
+        assert_eq!(r.rule("ab"), Match("ab", ""));
+    }
+
+    #[test]
+    fn slice() {
+        let r = super::take_while(u8::is_ascii_digit);
+        assert_eq!(r.rule(b"12ab".as_ref()), Match(b"12".as_ref(), b"ab".as_ref()));
+
+        let r = super::take_till(|&n: &i32| n < 0);
+        assert_eq!(r.rule([1, 2, -1].as_ref()), Match([1, 2].as_ref(), [-1].as_ref()));
+
+        let r = super::take_while_m_n(1, 2, |&n: &i32| n > 0);
+        assert_eq!(r.rule([1, 2, 3].as_ref()), Match([1, 2].as_ref(), [3].as_ref()));
+        assert_eq!(r.rule([0].as_ref()), Expected(Failed::Predicate));
+    }
+}
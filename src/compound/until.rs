@@ -28,7 +28,7 @@ impl<'r, I, R, U, C> Rule<'r, I> for Until<R, U, C>
     where
         R: Rule<'r, I>,
         U: Rule<'r, I>,
-        I: Copy,
+        I: Clone,
         C: Concat<C, R::Mat>,
 {
     type Mat = (C, U::Mat);
@@ -38,7 +38,7 @@ impl<'r, I, R, U, C> Rule<'r, I> for Until<R, U, C>
         let mut res = C::empty();
 
         loop {
-            match self.1.rule(input) {
+            match self.1.rule(input.clone()) {
                 Match(u, i) => break Ruled::Match((res, u), i),
                 Expected(_) => {
                     match self.0.rule(input) {
@@ -57,6 +57,7 @@ impl<'r, I, R, U, C> Rule<'r, I> for Until<R, U, C>
 impl_or!(Until<R, U, C>);
 impl_shifts!(Until<R, U, C>);
 impl_not!(Until<R, U, C>);
+impl_sub!(Until<R, U, C>);
 
 impl<R, U, T> std::ops::BitAnd<T> for Until<R, U, &'static str> {
     type Output = super::Cat<Until<R, U, &'static str>, T, &'static str>;
@@ -0,0 +1,107 @@
+use crate::{
+    prelude::*,
+    Consumed,
+    Span,
+    Spanned,
+};
+
+/// Wraps the match of the rule into [`Spanned`] with the consumed [`Span`].
+///
+/// [`Spanned`]: ../struct.Spanned.html
+/// [`Span`]: ../struct.Span.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::latin};
+/// let src = "let x";
+/// let r = latin().spanned();
+///
+/// let x = r.rule(&src[4..]).mat().unwrap();
+/// assert_eq!(x.value, "x");
+/// assert_eq!(x.span.range(src), 4..5);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct WithSpan<R>(pub R);
+
+impl<'r, I, R> Rule<'r, I> for WithSpan<R>
+    where
+        R: Rule<'r, I>,
+        I: Consumed,
+{
+    type Mat = Spanned<R::Mat>;
+    type Exp = R::Exp;
+
+    fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+        self.0.rule(input.clone())
+            .and_then(|value, rest| Match(Spanned { value, span: Span::new(input, rest.clone()) }, rest))
+    }
+}
+
+impl_ops!(WithSpan<R>);
+
+/// Applies the function to the match of the rule and the consumed [`Span`].
+///
+/// [`Span`]: ../struct.Span.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::dec};
+/// let src = "a 12";
+/// let r = dec().skip_many1().map_with_span(|_, span| span.range(src));
+///
+/// assert_eq!(r.rule(&src[2..]), Match(2..4, ""));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MapWithSpan<R, F>(pub R, pub F);
+
+impl<'r, I, R, F, K> Rule<'r, I> for MapWithSpan<R, F>
+    where
+        R: Rule<'r, I>,
+        F: Fn(R::Mat, Span) -> K,
+        I: Consumed,
+{
+    type Mat = K;
+    type Exp = R::Exp;
+
+    fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+        self.0.rule(input.clone())
+            .and_then(|m, rest| Match((self.1)(m, Span::new(input, rest.clone())), rest))
+    }
+}
+
+impl_ops!(MapWithSpan<R, F>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::{dec, latin, space};
+
+    #[test]
+    fn spanned() {
+        let src = "ab 12";
+        let r = (latin().skip_many1().spanned(), space(), dec().skip_many1().spanned());
+
+        let (a, _, b) = r.rule(src).mat().unwrap();
+        assert_eq!(a.span.range(src), 0..2);
+        assert_eq!(b.span.range(src), 3..5);
+        assert_eq!(a.span.join(&b.span).slice(src), src);
+
+        let src = "x\n  y";
+        let y = latin().spanned().rule(&src[4..]).mat().unwrap();
+        assert_eq!(y.span.line_col(src), (2, 3));
+    }
+
+    #[test]
+    fn map_with_span() {
+        let src = "12";
+        let r = dec().map_with_span(|d, span| (d, span.range(src)));
+        assert_eq!(r.rule(&src[1..]), Match(("2", 1..2), ""));
+        assert_eq!(r.rule("x"), Expected(Failed::Dec));
+
+        let src = b"ab".as_ref();
+        let r = vec![b'b'].map_with_span(|_, span| span.start(src));
+        assert_eq!(r.rule(&src[1..]), Match(1, b"".as_ref()));
+    }
+}
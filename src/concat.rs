@@ -26,10 +26,10 @@ impl<'l, 'r: 'l> Concat<&'l str, &'r str> for &'l str {
     fn empty() -> Self { "" }
 
     fn concat(l: &'l str, r: &'r str) -> Self {
-        if l.len() == 0 { return r }
-        if r.len() == 0 { return l }
+        if l.is_empty() { return r }
+        if r.is_empty() { return l }
 
-        if unsafe { l.as_ptr().offset(l.len() as isize) } != r.as_ptr() {
+        if unsafe { l.as_ptr().add(l.len()) } != r.as_ptr() {
             panic!("The trying to concat not adjacent string slices!")
         }
 
@@ -60,3 +60,25 @@ impl<T> Concat<T, T> for Vec<T> {
         v
     }
 }
+
+impl<R> Concat<(), R> for () {
+    fn empty() -> Self {}
+
+    fn concat(_: (), _: R) -> Self {}
+}
+
+/// The number of concatenated values.
+///
+/// Use it as the result of [`Range`] to count repetitions without collecting them.
+/// [`Rule::count`] returns the number as `usize`.
+///
+/// [`Range`]: ./compound/struct.Range.html
+/// [`Rule::count`]: ./trait.Rule.html#method.count
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Count(pub usize);
+
+impl<R> Concat<Count, R> for Count {
+    fn empty() -> Self { Count(0) }
+
+    fn concat(l: Count, _: R) -> Self { Count(l.0 + 1) }
+}
@@ -0,0 +1,30 @@
+/// An interface for getting the part of the input consumed by a rule.
+///
+/// Rules return the rest of the input after a match, which is always a suffix
+/// of the original input, so the consumed part is computed from the lengths only.
+pub trait Consumed: Clone {
+    /// Returns the length of the input.
+    fn rest_len(&self) -> usize;
+
+    /// Returns the prefix of `self` that precedes the `rest`.
+    fn consumed(self, rest: Self) -> Self;
+
+    /// Returns the suffix of `self` of the length `len`.
+    fn suffix(self, len: usize) -> Self;
+}
+
+impl Consumed for &str {
+    fn rest_len(&self) -> usize { self.len() }
+
+    fn consumed(self, rest: Self) -> Self { &self[..self.len() - rest.len()] }
+
+    fn suffix(self, len: usize) -> Self { &self[self.len() - len..] }
+}
+
+impl<T> Consumed for &[T] {
+    fn rest_len(&self) -> usize { self.len() }
+
+    fn consumed(self, rest: Self) -> Self { &self[..self.len() - rest.len()] }
+
+    fn suffix(self, len: usize) -> Self { &self[self.len() - len..] }
+}
@@ -0,0 +1,155 @@
+use crate::CharSet;
+
+/// An expression of a dynamic grammar.
+///
+/// References to rules are names in the parsed text and indices of rules
+/// in a built [`Grammar`].
+///
+/// [`Grammar`]: ./struct.Grammar.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expr<R = String> {
+    /// The literal string.
+    Literal(String),
+
+    /// Any char of the set.
+    Class(CharSet),
+
+    /// Any char.
+    Any,
+
+    /// The rule of the grammar.
+    Ref(R),
+
+    /// All expressions in order.
+    Seq(Vec<Expr<R>>),
+
+    /// The first matching expression.
+    Choice(Vec<Expr<R>>),
+
+    /// Optional expression.
+    Opt(Box<Expr<R>>),
+
+    /// Zero or more repetitions.
+    Many(Box<Expr<R>>),
+
+    /// One or more repetitions.
+    Many1(Box<Expr<R>>),
+
+    /// Positive lookahead.
+    Peek(Box<Expr<R>>),
+
+    /// Negative lookahead.
+    Not(Box<Expr<R>>),
+
+    /// The first expression if the second one doesn't match.
+    Except(Box<Expr<R>>, Box<Expr<R>>),
+}
+
+impl<R> Expr<R> {
+    /// Creates a sequence, or returns the expression if it's the only one.
+    pub(crate) fn seq(mut items: Vec<Expr<R>>) -> Self {
+        match items.len() {
+            1 => items.remove(0),
+            _ => Expr::Seq(items),
+        }
+    }
+
+    /// Creates a choice, or returns the expression if it's the only one.
+    pub(crate) fn choice(first: Expr<R>, rest: impl IntoIterator<Item = Expr<R>>) -> Self {
+        let mut alts = vec![first];
+        alts.extend(rest);
+
+        match alts.len() {
+            1 => alts.remove(0),
+            _ => Expr::Choice(alts),
+        }
+    }
+
+    /// Applies the suffix operator `*`, `+` or `?`.
+    pub(crate) fn suffixed(self, suffix: Option<&str>) -> Self {
+        match suffix {
+            Some("*") => Expr::Many(Box::new(self)),
+            Some("+") => Expr::Many1(Box::new(self)),
+            Some("?") => Expr::Opt(Box::new(self)),
+            _ => self,
+        }
+    }
+
+    /// Replaces references to rules using the function.
+    pub(crate) fn resolve<S, E, F>(self, f: &mut F) -> Result<Expr<S>, E>
+        where
+            F: FnMut(R) -> Result<S, E>,
+    {
+        let boxed = |e: Box<Expr<R>>, f: &mut F| -> Result<_, E> { e.resolve(f).map(Box::new) };
+        let all = |es: Vec<Expr<R>>, f: &mut F| -> Result<Vec<_>, E> {
+            es.into_iter().map(|e| e.resolve(f)).collect()
+        };
+
+        Ok(match self {
+            Expr::Literal(s) => Expr::Literal(s),
+            Expr::Class(set) => Expr::Class(set),
+            Expr::Any => Expr::Any,
+            Expr::Ref(r) => Expr::Ref(f(r)?),
+            Expr::Seq(es) => Expr::Seq(all(es, f)?),
+            Expr::Choice(es) => Expr::Choice(all(es, f)?),
+            Expr::Opt(e) => Expr::Opt(boxed(e, f)?),
+            Expr::Many(e) => Expr::Many(boxed(e, f)?),
+            Expr::Many1(e) => Expr::Many1(boxed(e, f)?),
+            Expr::Peek(e) => Expr::Peek(boxed(e, f)?),
+            Expr::Not(e) => Expr::Not(boxed(e, f)?),
+            Expr::Except(a, b) => Expr::Except(boxed(a, f)?, boxed(b, f)?),
+        })
+    }
+}
+
+impl Expr<usize> {
+    /// Returns `true` if the expression can match without consuming input.
+    pub(crate) fn nullable(&self, rules: &[bool]) -> bool {
+        match self {
+            Expr::Literal(s) => s.is_empty(),
+            Expr::Class(_) | Expr::Any => false,
+            Expr::Ref(id) => rules[*id],
+            Expr::Seq(es) => es.iter().all(|e| e.nullable(rules)),
+            Expr::Choice(es) => es.iter().any(|e| e.nullable(rules)),
+            Expr::Opt(_) | Expr::Many(_) | Expr::Peek(_) | Expr::Not(_) => true,
+            Expr::Many1(e) | Expr::Except(e, _) => e.nullable(rules),
+        }
+    }
+
+    /// Calls the function for every rule that can be applied at the start of the input.
+    pub(crate) fn first_refs(&self, nullable: &[bool], f: &mut impl FnMut(usize)) {
+        match self {
+            Expr::Literal(_) | Expr::Class(_) | Expr::Any => {}
+            Expr::Ref(id) => f(*id),
+            Expr::Seq(es) => for e in es {
+                e.first_refs(nullable, f);
+
+                if !e.nullable(nullable) {
+                    break;
+                }
+            },
+            Expr::Choice(es) => es.iter().for_each(|e| e.first_refs(nullable, f)),
+            Expr::Opt(e) | Expr::Many(e) | Expr::Many1(e) | Expr::Peek(e) | Expr::Not(e) => {
+                e.first_refs(nullable, f)
+            }
+            Expr::Except(a, b) => {
+                b.first_refs(nullable, f);
+                a.first_refs(nullable, f);
+            }
+        }
+    }
+
+    /// Calls the function for every rule referenced in the expression.
+    pub(crate) fn refs(&self, f: &mut impl FnMut(usize)) {
+        match self {
+            Expr::Literal(_) | Expr::Class(_) | Expr::Any => {}
+            Expr::Ref(id) => f(*id),
+            Expr::Seq(es) | Expr::Choice(es) => es.iter().for_each(|e| e.refs(f)),
+            Expr::Opt(e) | Expr::Many(e) | Expr::Many1(e) | Expr::Peek(e) | Expr::Not(e) => e.refs(f),
+            Expr::Except(a, b) => {
+                a.refs(f);
+                b.refs(f);
+            }
+        }
+    }
+}
@@ -0,0 +1,438 @@
+use std::collections::HashMap;
+
+use crate::{
+    prelude::*,
+    basic::any,
+    Consumed,
+    ParseTree,
+    Span,
+};
+use super::{syntax, Expr};
+
+/// An error of loading a dynamic grammar.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GrammarError {
+    /// Invalid syntax at 1-based line and column in chars.
+    Syntax {
+        line: usize,
+        col: usize,
+    },
+
+    /// A class range ending before its start at 1-based line and column in chars.
+    ReversedRange {
+        line: usize,
+        col: usize,
+    },
+
+    /// The grammar has no rules.
+    Empty,
+
+    /// The rule is defined more than once.
+    Duplicate(String),
+
+    /// The rule refers to an undefined name.
+    Undefined {
+        rule: String,
+        name: String,
+    },
+
+    /// The rule isn't reachable from the start rule.
+    Unreachable(String),
+
+    /// The rule can call itself without consuming input.
+    LeftRecursion(String),
+}
+
+impl std::fmt::Display for GrammarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrammarError::Syntax { line, col } => write!(f, "invalid syntax at {}:{}", line, col),
+            GrammarError::ReversedRange { line, col } => write!(f, "reversed class range at {}:{}", line, col),
+            GrammarError::Empty => write!(f, "the grammar has no rules"),
+            GrammarError::Duplicate(rule) => write!(f, "rule `{}` is defined more than once", rule),
+            GrammarError::Undefined { rule, name } => write!(f, "rule `{}` refers to undefined `{}`", rule, name),
+            GrammarError::Unreachable(rule) => write!(f, "rule `{}` is unreachable", rule),
+            GrammarError::LeftRecursion(rule) => write!(f, "rule `{}` is left recursive", rule),
+        }
+    }
+}
+
+impl std::error::Error for GrammarError {}
+
+/// A grammar loaded from text at runtime.
+///
+/// The grammar is written in PEG or EBNF notation and parsed by the rules of this crate.
+/// The first rule is the start rule. Loading fails if a rule refers to an undefined name,
+/// isn't reachable from the start rule, is defined twice or is left recursive.
+/// A syntax error is reported at the furthest position the text was parsed to,
+/// and a class range such as `[z-a]` is rejected instead of matching nothing.
+///
+/// The grammar is a rule itself that applies the start rule, and [`get`] returns
+/// any other rule. They parse `&str` into a [`ParseTree`] where every applied rule
+/// is a node with its name and span. Literals and classes don't produce nodes.
+/// Fails with the error of the last tried alternative, like [`Or`].
+///
+/// PEG notation:
+///
+/// | Expression        | Meaning                                  |
+/// |:------------------|:-----------------------------------------|
+/// | `name <- e`       | The rule definition                      |
+/// | `'lit'`, `"lit"`  | The literal with `\n`, `\r`, `\t` escapes |
+/// | `[a-z_]`, `[^"]`  | The char class and its complement        |
+/// | `.`               | Any char                                 |
+/// | `e1 e2`           | Sequence                                 |
+/// | `e1 / e2`         | Ordered choice                           |
+/// | `e*`, `e+`, `e?`  | Repetition and option                    |
+/// | `&e`, `!e`        | Positive and negative lookahead          |
+/// | `# comment`       | The comment until the end of the line    |
+///
+/// EBNF notation defines rules as `name = e ;` or `name ::= e` with an optional
+/// `;` or `.` terminator. It has literals, `,` or whitespace separated sequences,
+/// `|` choice, `[e]` option, `{e}` repetition, `e1 - e2` exception, the suffixes
+/// `*`, `+`, `?` and `(* comments *)`.
+///
+/// [`get`]: ./struct.Grammar.html#method.get
+/// [`ParseTree`]: ../struct.ParseTree.html
+/// [`Or`]: ../compound/struct.Or.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, dynamic::Grammar};
+/// let grammar = Grammar::peg(r#"
+///     list <- '[' (num (',' num)*)? ']'
+///     num  <- [0-9]+
+/// "#).unwrap();
+///
+/// let tree = grammar.rule("[1,23]").mat().unwrap();
+/// assert_eq!(tree.name, "list");
+/// assert_eq!(tree.children.iter().map(|n| n.text).collect::<Vec<_>>(), ["1", "23"]);
+/// assert_eq!(tree.children[1].span.range("[1,23]"), 3..5);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grammar {
+    rules: Vec<(String, Expr<usize>)>,
+}
+
+impl Grammar {
+    /// Loads the grammar in PEG notation.
+    pub fn peg(text: &str) -> Result<Self, GrammarError> { Grammar::build(syntax::parse_peg(text)?) }
+
+    /// Loads the grammar in EBNF notation.
+    pub fn ebnf(text: &str) -> Result<Self, GrammarError> { Grammar::build(syntax::parse_ebnf(text)?) }
+
+    /// Returns the names of the rules in the definition order.
+    pub fn names(&self) -> impl Iterator<Item = &str> { self.rules.iter().map(|(name, _)| name.as_str()) }
+
+    /// Returns the rule with the name.
+    pub fn get(&self, name: &str) -> Option<GrammarRule<'_>> {
+        self.rules.iter()
+            .position(|(n, _)| n == name)
+            .map(|id| GrammarRule { grammar: self, id })
+    }
+
+    /// Resolves the names and validates the rules.
+    fn build(defs: Vec<(String, Expr)>) -> Result<Self, GrammarError> {
+        let mut ids = HashMap::new();
+
+        for (id, (name, _)) in defs.iter().enumerate() {
+            if ids.insert(name.clone(), id).is_some() {
+                return Err(GrammarError::Duplicate(name.clone()));
+            }
+        }
+
+        let rules = defs.into_iter()
+            .map(|(rule, expr)| {
+                let expr = expr.resolve(&mut |name: String| match ids.get(&name) {
+                    Some(&id) => Ok(id),
+                    None => Err(GrammarError::Undefined { rule: rule.clone(), name }),
+                })?;
+
+                Ok((rule, expr))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let grammar = Grammar { rules };
+        grammar.check_reachable()?;
+        grammar.check_left_recursion()?;
+        Ok(grammar)
+    }
+
+    fn check_reachable(&self) -> Result<(), GrammarError> {
+        if self.rules.is_empty() {
+            return Err(GrammarError::Empty);
+        }
+
+        let mut reached = vec![false; self.rules.len()];
+        let mut stack = vec![0];
+        reached[0] = true;
+
+        while let Some(id) = stack.pop() {
+            self.rules[id].1.refs(&mut |next| if !reached[next] {
+                reached[next] = true;
+                stack.push(next);
+            });
+        }
+
+        match reached.iter().position(|&r| !r) {
+            Some(id) => Err(GrammarError::Unreachable(self.rules[id].0.clone())),
+            None => Ok(()),
+        }
+    }
+
+    fn check_left_recursion(&self) -> Result<(), GrammarError> {
+        let mut nullable = vec![false; self.rules.len()];
+
+        loop {
+            let mut changed = false;
+
+            for (id, (_, expr)) in self.rules.iter().enumerate() {
+                if !nullable[id] && expr.nullable(&nullable) {
+                    nullable[id] = true;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        for (id, (name, _)) in self.rules.iter().enumerate() {
+            let mut visited = vec![false; self.rules.len()];
+            let mut stack = vec![id];
+
+            while let Some(cur) = stack.pop() {
+                let mut recursive = false;
+
+                self.rules[cur].1.first_refs(&nullable, &mut |next| {
+                    recursive |= next == id;
+
+                    if !visited[next] {
+                        visited[next] = true;
+                        stack.push(next);
+                    }
+                });
+
+                if recursive {
+                    return Err(GrammarError::LeftRecursion(name.clone()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies the rule and returns its node.
+    fn apply<'r, 'i>(&'r self, id: usize, input: &'i str) -> Ruled<&'i str, ParseTree<'r, 'i>, Failed<'r>> {
+        let (name, expr) = &self.rules[id];
+        let mut children = Vec::new();
+
+        self.eval(expr, input, &mut children)
+            .and_then(|_, rest| Match(ParseTree {
+                name,
+                span: Span::new(input, rest),
+                text: input.consumed(rest),
+                children,
+            }, rest))
+    }
+
+    /// Applies the expression and appends nodes of the applied rules.
+    fn eval<'r, 'i>(
+        &'r self,
+        expr: &'r Expr<usize>,
+        input: &'i str,
+        nodes: &mut Vec<ParseTree<'r, 'i>>,
+    ) -> Ruled<&'i str, (), Failed<'r>> {
+        let len = nodes.len();
+
+        let res = match expr {
+            Expr::Literal(s) => s.rule(input).map(|_| ()),
+            Expr::Class(set) => set.rule(input).map(|_| ()),
+            Expr::Any => any().rule(input).map(|_| ()),
+            Expr::Ref(id) => self.apply(*id, input).map(|node| nodes.push(node)),
+            Expr::Seq(es) => {
+                let mut rest = input;
+
+                for e in es {
+                    match self.eval(e, rest, nodes) {
+                        Match(_, r) => rest = r,
+                        Expected(e) => {
+                            nodes.truncate(len);
+                            return Expected(e);
+                        }
+                    }
+                }
+
+                Match((), rest)
+            }
+            Expr::Choice(es) => {
+                let mut exp = Failed::Nothing;
+
+                for e in es {
+                    match self.eval(e, input, nodes) {
+                        Match(_, rest) => return Match((), rest),
+                        Expected(e) => exp = e,
+                    }
+                }
+
+                Expected(exp)
+            }
+            Expr::Opt(e) => match self.eval(e, input, nodes) {
+                Match(_, rest) => Match((), rest),
+                Expected(_) => Match((), input),
+            },
+            Expr::Many(e) => Match((), self.repeat(e, input, nodes)),
+            Expr::Many1(e) => match self.eval(e, input, nodes) {
+                Match(_, rest) => Match((), self.repeat(e, rest, nodes)),
+                Expected(e) => Expected(e),
+            },
+            Expr::Peek(e) => {
+                let res = self.eval(e, input, nodes);
+                nodes.truncate(len);
+                res.and_then(|_, _| Match((), input))
+            }
+            Expr::Not(e) => match self.eval(e, input, nodes) {
+                Match(_, rest) => Expected(Failed::Unexpected(input.len() - rest.len())),
+                Expected(_) => Match((), input),
+            },
+            Expr::Except(a, b) => match self.eval(b, input, nodes) {
+                Match(_, rest) => Expected(Failed::Unexpected(input.len() - rest.len())),
+                Expected(_) => self.eval(a, input, nodes),
+            },
+        };
+
+        if res.is_expected() {
+            nodes.truncate(len);
+        }
+
+        res
+    }
+
+    /// Applies the expression while it matches and consumes input.
+    fn repeat<'r, 'i>(&'r self, expr: &'r Expr<usize>, mut input: &'i str, nodes: &mut Vec<ParseTree<'r, 'i>>) -> &'i str {
+        loop {
+            let len = nodes.len();
+
+            match self.eval(expr, input, nodes) {
+                Match(_, rest) if rest.len() < input.len() => input = rest,
+                _ => {
+                    nodes.truncate(len);
+                    return input;
+                }
+            }
+        }
+    }
+}
+
+impl<'r, 'i> Rule<'r, &'i str> for Grammar {
+    type Mat = ParseTree<'r, 'i>;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> { self.apply(0, input) }
+}
+
+/// A rule of the [`Grammar`]. Created with [`Grammar::get`].
+///
+/// [`Grammar`]: ./struct.Grammar.html
+/// [`Grammar::get`]: ./struct.Grammar.html#method.get
+#[derive(Copy, Clone, Debug)]
+pub struct GrammarRule<'g> {
+    grammar: &'g Grammar,
+    id: usize,
+}
+
+impl<'r, 'i, 'g> Rule<'r, &'i str> for GrammarRule<'g>
+    where
+        'g: 'r,
+{
+    type Mat = ParseTree<'g, 'i>;
+    type Exp = Failed<'g>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> { self.grammar.apply(self.id, input) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names<'n>(tree: &ParseTree<'n, '_>) -> Vec<&'n str> { tree.children.iter().map(|n| n.name).collect() }
+
+    #[test]
+    fn peg() {
+        let g = Grammar::peg(r#"
+            # Arithmetic
+            top    <- expr !.
+            expr   <- term (op term)*
+            term   <- num / '(' expr ')'
+            op     <- [-+*/]
+            num    <- '-'? [0-9]+ ![a-z_]
+        "#).unwrap();
+        assert_eq!(g.names().collect::<Vec<_>>(), ["top", "expr", "term", "op", "num"]);
+
+        let top = g.rule("1+(2*-3)").mat().unwrap();
+        let tree = &top.children[0];
+        assert_eq!(names(tree), ["term", "op", "term"]);
+        assert_eq!(tree.children[2].text, "(2*-3)");
+        assert_eq!(names(&tree.children[2].children[0]), ["term", "op", "term"]);
+
+        assert_eq!(g.rule("1+2").mat().unwrap().to_sexp(),
+            r#"(top (expr (term (num "1")) (op "+") (term (num "2"))))"#);
+        assert_eq!(g.rule("1+"), Expected(Failed::Unexpected(1)));
+        assert_eq!(g.rule("1x"), Expected(Failed::Str("(")));
+        assert_eq!(g.get("num").unwrap().rule("-12+"), Match(ParseTree {
+            name: "num",
+            span: Span::new("-12+", "+"),
+            text: "-12",
+            children: vec![],
+        }, "+"));
+        assert!(g.get("none").is_none());
+    }
+
+    #[test]
+    fn peg_syntax() {
+        let g = Grammar::peg(r#"s <- "a\"\n" [^\]a-c] &'x' .  "#).unwrap();
+        assert!(g.rule("a\"\n!x").is_match());
+        assert!(g.rule("a\"\nbx").is_expected());
+        assert!(g.rule("a\"\n]x").is_expected());
+
+        assert_eq!(Grammar::peg("a <- 'x"), Err(GrammarError::Syntax { line: 1, col: 8 }));
+        assert_eq!(Grammar::peg("a <- b\n  b <- (c"), Err(GrammarError::Syntax { line: 2, col: 10 }));
+        assert_eq!(Grammar::peg("a <- b\nb <- [a-z"), Err(GrammarError::Syntax { line: 2, col: 10 }));
+        assert_eq!(Grammar::peg("a <- b )"), Err(GrammarError::Syntax { line: 1, col: 8 }));
+        assert_eq!(Grammar::peg("a -> b"), Err(GrammarError::Syntax { line: 1, col: 3 }));
+        assert_eq!(Grammar::peg("a <- 'x' [_z-a]"), Err(GrammarError::ReversedRange { line: 1, col: 12 }));
+        assert!(Grammar::peg("a <- [a-a]").is_ok());
+    }
+
+    #[test]
+    fn ebnf() {
+        let g = Grammar::ebnf(r#"
+            (* Comma separated letters *)
+            list   ::= letter { ',' letter } ;
+            letter = [ '^' ], (ascii - 'q') .
+            ascii  = 'a' | 'b' | 'q'
+        "#).unwrap();
+
+        let tree = g.rule("a,^b,q").mat().unwrap();
+        assert_eq!(tree.text, "a,^b");
+        assert_eq!(names(&tree), ["letter", "letter"]);
+        assert_eq!(names(&tree.children[1]), ["ascii"]);
+        assert_eq!(g.rule("q"), Expected(Failed::Unexpected(1)));
+        assert_eq!(Grammar::ebnf("a = 'x' | (b"), Err(GrammarError::Syntax { line: 1, col: 13 }));
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(Grammar::peg(""), Err(GrammarError::Empty));
+        assert_eq!(Grammar::peg("a <- b"), Err(GrammarError::Undefined {
+            rule: "a".to_string(),
+            name: "b".to_string(),
+        }));
+        assert_eq!(Grammar::peg("a <- 'x'\nb <- 'y'"), Err(GrammarError::Unreachable("b".to_string())));
+        assert_eq!(Grammar::peg("a <- 'x'\na <- 'y'"), Err(GrammarError::Duplicate("a".to_string())));
+        assert_eq!(Grammar::peg("a <- b 'x'\nb <- 'y'? a"), Err(GrammarError::LeftRecursion("a".to_string())));
+        assert_eq!(Grammar::ebnf("a = { 'x' } , a"), Err(GrammarError::LeftRecursion("a".to_string())));
+        assert!(Grammar::peg("a <- 'x' a / 'y'").is_ok());
+    }
+}
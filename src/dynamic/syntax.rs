@@ -0,0 +1,216 @@
+use std::cell::Cell;
+
+use crate::{
+    prelude::*,
+    basic::{any, dec, latin, white},
+    compound::lift,
+    CharSet,
+    Span,
+    State,
+    Stateful,
+};
+use super::{Expr, GrammarError};
+
+/// The positions recorded while parsing definitions, as lengths of the rest of the text.
+///
+/// No rule modifies the state, so every input refers to the same cells
+/// and the positions recorded in abandoned branches are kept.
+#[derive(Debug)]
+struct Marks {
+    /// The shortest rest where a token was expected.
+    furthest: Cell<usize>,
+
+    /// The rest at the first reversed class range.
+    reversed: Cell<Option<usize>>,
+}
+
+type Input<'i> = Stateful<&'i str, Marks>;
+
+/// Records the position where the next token is expected and matches nothing.
+///
+/// A failed definition reports the furthest recorded position,
+/// since the rules backtrack to the start of the definition.
+#[derive(Copy, Clone, Debug)]
+struct Mark;
+
+impl<'r, 'i> Rule<'r, Input<'i>> for Mark {
+    type Mat = ();
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: Input<'i>) -> Ruled<Input<'i>, Self::Mat, Self::Exp> {
+        let len = input.input().len();
+        input.with(|m| m.furthest.set(m.furthest.get().min(len)));
+        Match((), input)
+    }
+}
+
+/// Matches nothing and returns the input, so an action can record a position.
+#[derive(Copy, Clone, Debug)]
+struct Here;
+
+impl<'r, 'i> Rule<'r, Input<'i>> for Here {
+    type Mat = Input<'i>;
+    type Exp = Failed<'static>;
+
+    fn rule(&'r self, input: Input<'i>) -> Ruled<Input<'i>, Self::Mat, Self::Exp> { Match(input.clone(), input) }
+}
+
+/// Replaces the escaped char with its value.
+fn unescape(c: &str) -> char {
+    match c {
+        "n" => '\n',
+        "r" => '\r',
+        "t" => '\t',
+        "0" => '\0',
+        _ => c.chars().next().unwrap_or_default(),
+    }
+}
+
+crate::grammar! {
+    mod lexical<Marks>;
+
+    name: &'i str = { Rule::recognize(name_chars()) } => |n: Input<'i>| n.input();
+    name_chars: () = name_start name_continue* => |_| ();
+    name_start: &'i str = { lift(latin()) } / '_';
+    name_continue: &'i str = name_start / { lift(dec()) };
+    literal: Expr = '\'' (!'\'' chr)* { Mark } '\'' => |(_, cs, _, _)| literal_expr(cs)
+        / '"' (!'"' chr)* { Mark } '"' => |(_, cs, _, _)| literal_expr(cs);
+    chr: char = '\\' { lift(any()) } => |(_, c)| unescape(c)
+        / { lift(any()) } => |c: &str| c.chars().next().unwrap_or_default();
+    suffix: &'i str = '*' / '+' / '?';
+}
+
+/// Collects the chars of a literal.
+fn literal_expr(chars: Vec<((), char)>) -> Expr { Expr::Literal(chars.into_iter().map(|(_, c)| c).collect()) }
+
+/// Creates the set of a class range, a reversed range is recorded as an error.
+fn range_set(at: Input, from: char, to: char) -> CharSet {
+    if from > to {
+        let len = at.input().len();
+        at.with(|m| if m.reversed.get().is_none() {
+            m.reversed.set(Some(len));
+        });
+    }
+
+    char_range(from..=to).into()
+}
+
+/// Merges the ranges of a class.
+fn class_expr(negated: Option<&str>, ranges: Vec<((), CharSet)>) -> Expr {
+    let set = ranges.into_iter().fold(CharSet::new(), |set, (_, r)| set.union(&r));
+
+    Expr::Class(if negated.is_some() { set.complement() } else { set })
+}
+
+crate::grammar! {
+    mod peg<Marks>;
+
+    header: &'i str = { lexical::name() } sp "<-" sp => |(n, _, _, _)| n;
+    choice: Expr = seq ('/' sp seq)* => |(first, rest)| Expr::choice(first, rest.into_iter().map(|(_, _, s)| s));
+    seq: Expr = item* => Expr::seq;
+    item: Expr = '&' sp suffixed => |(_, _, e)| Expr::Peek(Box::new(e))
+        / '!' sp suffixed => |(_, _, e)| Expr::Not(Box::new(e))
+        / suffixed;
+    suffixed: Expr = primary ({ lexical::suffix() } sp)? => |(e, s)| Expr::suffixed(e, s.map(|(s, _)| s));
+    primary: Expr = reference / group / literal / class / dot;
+    reference: Expr = { lexical::name() } sp !"<-" => |(n, _, _)| Expr::Ref(n.to_string());
+    group: Expr = '(' sp choice ')' sp => |(_, _, e, _, _)| e;
+    literal: Expr = { lexical::literal() } sp => |(l, _)| l;
+    class: Expr = '[' '^'? (!']' range)* { Mark } ']' sp => |(_, neg, ranges, _, _, _)| class_expr(neg, ranges);
+    dot: Expr = '.' sp => |_| Expr::Any;
+    range: CharSet = { Here } { lexical::chr() } '-' !']' { lexical::chr() } => |(at, a, _, _, b)| range_set(at, a, b)
+        / { lexical::chr() } => CharSet::from;
+    sp: () = ({ lift(white()) } => |_| () / comment)* { Mark } => |_| ();
+    comment: () = '#' (!'\n' { lift(any()) })* => |_| ();
+}
+
+crate::grammar! {
+    mod ebnf<Marks>;
+
+    header: &'i str = { lexical::name() } sp define sp => |(n, _, _, _)| n;
+    define: &'i str = "::=" / '=';
+    body: Expr = choice ((';' / '.') sp)? => |(e, _)| e;
+    choice: Expr = seq ('|' sp seq)* => |(first, rest)| Expr::choice(first, rest.into_iter().map(|(_, _, s)| s));
+    seq: Expr = (term (',' sp)?)* => |items| Expr::seq(items.into_iter().map(|(t, _)| t).collect());
+    term: Expr = factor ('-' sp factor)? => |(a, b)| match b {
+        Some((_, _, b)) => Expr::Except(Box::new(a), Box::new(b)),
+        None => a,
+    };
+    factor: Expr = primary ({ lexical::suffix() } sp)? => |(e, s)| Expr::suffixed(e, s.map(|(s, _)| s));
+    primary: Expr = reference / group / option / repetition / literal;
+    reference: Expr = { lexical::name() } sp !define => |(n, _, _)| Expr::Ref(n.to_string());
+    group: Expr = '(' sp choice ')' sp => |(_, _, e, _, _)| e;
+    option: Expr = '[' sp choice ']' sp => |(_, _, e, _, _)| Expr::Opt(Box::new(e));
+    repetition: Expr = '{' sp choice '}' sp => |(_, _, e, _, _)| Expr::Many(Box::new(e));
+    literal: Expr = { lexical::literal() } sp => |(l, _)| l;
+    sp: () = ({ lift(white()) } => |_| () / comment)* { Mark } => |_| ();
+    comment: () = "(*" (!"*)" { lift(any()) })* "*)" => |_| ();
+}
+
+/// Parses the definitions of a PEG grammar.
+pub(super) fn parse_peg(text: &str) -> Result<Vec<(String, Expr)>, GrammarError> {
+    definitions(text, |s| peg::sp().rule(s), |s| peg::header().rule(s), |s| peg::choice().rule(s))
+}
+
+/// Parses the definitions of an EBNF grammar.
+pub(super) fn parse_ebnf(text: &str) -> Result<Vec<(String, Expr)>, GrammarError> {
+    definitions(text, |s| ebnf::sp().rule(s), |s| ebnf::header().rule(s), |s| ebnf::body().rule(s))
+}
+
+/// Parses headers and bodies of definitions until the end of the text.
+fn definitions<'i, S, H, B>(
+    text: &'i str,
+    start: S,
+    header: H,
+    body: B,
+) -> Result<Vec<(String, Expr)>, GrammarError>
+    where
+        S: Fn(Input<'i>) -> Ruled<Input<'i>, (), Failed<'static>>,
+        H: Fn(Input<'i>) -> Ruled<Input<'i>, &'i str, Failed<'static>>,
+        B: Fn(Input<'i>) -> Ruled<Input<'i>, Expr, Failed<'static>>,
+{
+    let line_col = |len: usize| {
+        let rest = &text[text.len() - len..];
+        Span::new(rest, rest).line_col(text)
+    };
+    let error = |rest: &Input| {
+        let (line, col) = line_col(rest.with(|m| m.furthest.get()).min(rest.input().len()));
+        GrammarError::Syntax { line, col }
+    };
+
+    let state = State::new(Marks {
+        furthest: Cell::new(usize::MAX),
+        reversed: Cell::new(None),
+    });
+
+    let mut rest = match start(state.input(text)) {
+        Match(_, rest) => rest,
+        Expected(_) => state.input(text),
+    };
+    let mut defs = Vec::new();
+
+    while !rest.input().is_empty() {
+        let name = match header(rest.clone()) {
+            Match(name, r) => {
+                rest = r;
+                name
+            }
+            Expected(_) => return Err(error(&rest)),
+        };
+
+        match body(rest.clone()) {
+            Match(expr, r) => {
+                rest = r;
+                defs.push((name.to_string(), expr));
+            }
+            Expected(_) => return Err(error(&rest)),
+        }
+
+        if let Some(len) = rest.with(|m| m.reversed.get()) {
+            let (line, col) = line_col(len);
+            return Err(GrammarError::ReversedRange { line, col });
+        }
+    }
+
+    Ok(defs)
+}
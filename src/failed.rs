@@ -14,12 +14,39 @@ pub enum Failed<'r, U = ()> {
     /// The string reference.
     Str(&'r str),
 
+    /// The `char` value ignoring the case.
+    CharNoCase(char),
+
+    /// The string reference ignoring the case.
+    StrNoCase(&'r str),
+
     /// The char range.
     CharRange(std::ops::Bound<char>, std::ops::Bound<char>),
 
+    /// The byte string.
+    Bytes(&'r [u8]),
+
+    /// Any byte of the set.
+    AnyByte(&'r [u8]),
+
+    /// The char set.
+    CharSet(&'r crate::CharSet),
+
+    /// The keyword not followed by an identifier char.
+    Keyword(&'r str),
+
+    /// One of the keywords.
+    Keywords(&'r [&'r str]),
+
     /// Predicate checked.
     Predicate,
 
+    /// The user state checked.
+    State,
+
+    /// The indentation level.
+    Indent,
+
     /// Any char.
     AnyChar,
 
@@ -35,15 +62,61 @@ pub enum Failed<'r, U = ()> {
     /// The hexadecimal char (`0..=9` or `a..=f` or `A..=F`).
     Hex,
 
+    /// The digit of the radix.
+    Digit(u32),
+
+    /// The number doesn't fit the type.
+    Overflow,
+
+    /// The floating-point number.
+    Float,
+
+    /// A valid escape sequence at the byte position.
+    Escape(usize),
+
     /// Any latin char.
     Latin,
 
+    /// Any alphabetic char.
+    Alphabetic,
+
+    /// Any alphabetic or numeric char.
+    Alphanumeric,
+
+    /// Any numeric char.
+    Numeric,
+
+    /// Any uppercase char.
+    Uppercase,
+
+    /// Any lowercase char.
+    Lowercase,
+
+    /// Any char with the Unicode `White_Space` property.
+    UnicodeWhite,
+
+    /// Any control char.
+    Control,
+
+    /// Any char with the Unicode `XID_Start` property.
+    XidStart,
+
+    /// Any char with the Unicode `XID_Continue` property.
+    XidContinue,
+
     /// The new line.
     Nl,
 
     /// Any whitespace char.
     White,
 
+    /// An unwanted match at the failure position.
+    ///
+    /// Holds the length of the unwanted match in the units of the input,
+    /// bytes for `&str` and elements for slices. The matched text
+    /// starts at the input the failed rule was applied to.
+    Unexpected(usize),
+
     /// End of input.
     End,
 
@@ -0,0 +1,303 @@
+/// Declares a module of rules from a PEG grammar.
+///
+/// The grammar starts with the module declaration followed by rules of the form
+/// `name: Type = expression;`. Every rule becomes a type and a function of the same name
+/// in the module, so the rules can refer to each other and recurse.
+/// A rule parses `&'i str` input, where `'i` can be used in the `Type`,
+/// and fails with `Failed<'static>`. The rules are public, so the types they return must be too.
+///
+/// The header `mod name<S>;` makes the rules parse [`Stateful`] input of `&'i str`
+/// with the state `S`. Literals are lifted to it, while `{ expr }` rules have to parse
+/// the stateful input, e.g. with [`lift`] and [`Rule::update`].
+///
+/// Unlike the plain `name = expression` notation of PEG, every rule states its match type.
+/// A rule is a type implementing [`Rule`], and Rust can't infer its associated `Mat` type
+/// from the body, which may refer back to the rule itself. The `mod` header names the module
+/// holding the rules, since a macro can't make up an item name.
+///
+/// | Expression        | Meaning                                      | Built on          |
+/// |:------------------|:---------------------------------------------|:------------------|
+/// | `"lit"`, `'c'`    | The literal                                  | `str`, `char`     |
+/// | `name`            | The rule of the grammar                      |                   |
+/// | `{ expr }`        | Any Rust rule failing with `Failed<'static>` |                   |
+/// | `(e)`             | Grouping                                     |                   |
+/// | `e1 e2`           | Sequence, returns a tuple                    | tuples            |
+/// | `e1 / e2`         | Ordered choice                               | [`Or`]            |
+/// | `e*`, `e+`        | Repetition, returns a `Vec`                  | [`Range`]         |
+/// | `e?`              | Option                                       | [`Opt`]           |
+/// | `&e`              | Positive lookahead                           | [`Peek`]          |
+/// | `!e`              | Negative lookahead                           | [`NotFollowedBy`] |
+/// | `e1 e2 => action` | Applies the closure to the sequence result   | [`Map`]           |
+///
+/// An action belongs to one alternative and extends to the next `/` or `;`,
+/// so an action containing `/` must be in braces.
+/// Long rules may need a larger `recursion_limit`.
+///
+/// [`Rule`]: ./trait.Rule.html
+/// [`Stateful`]: ./struct.Stateful.html
+/// [`lift`]: ./compound/fn.lift.html
+/// [`Rule::update`]: ./trait.Rule.html#method.update
+/// [`Or`]: ./compound/struct.Or.html
+/// [`Range`]: ./compound/struct.Range.html
+/// [`Opt`]: ./compound/struct.Opt.html
+/// [`Peek`]: ./compound/struct.Peek.html
+/// [`NotFollowedBy`]: ./compound/struct.NotFollowedBy.html
+/// [`Map`]: ./compound/struct.Map.html
+///
+/// # Examples
+///
+/// ```
+/// use parsers::{prelude::*, grammar};
+///
+/// grammar! {
+///     mod calc;
+///
+///     expr: i64 = term (('+' / '-') term)* => |(first, rest)| rest.into_iter()
+///         .fold(first, |acc, (op, n)| if op == "+" { acc + n } else { acc - n });
+///     term: i64 = atom (('*' / '/') atom)* => |(first, rest)| rest.into_iter()
+///         .fold(first, |acc, (op, n)| { if op == "*" { acc * n } else { acc / n } });
+///     atom: i64 = { parsers::basic::int() } / '(' expr ')' => |(_, n, _)| n;
+/// }
+///
+/// assert_eq!(calc::expr().rule("2*(3+4)-10/5"), Match(12, ""));
+/// assert!(calc::expr().rule("(1").is_expected());
+/// ```
+#[macro_export]
+macro_rules! grammar {
+    ($vis:vis mod $name:ident; $($rules:tt)*) => {
+        $crate::grammar!(@mod [$vis] $name [&'i str;] $($rules)*);
+    };
+
+    ($vis:vis mod $name:ident<$state:ty>; $($rules:tt)*) => {
+        $crate::grammar!(@mod [$vis] $name
+            [$crate::Stateful<&'i str, $state>; $crate::compound::lift] $($rules)*);
+    };
+
+    // The context holds the input type and the path lifting literals to it
+    (@mod [$vis:vis] $name:ident $ctx:tt $($rules:tt)*) => {
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::grammar!(@rules $ctx $($rules)*);
+        }
+    };
+
+    // Splits rules by `;`
+    (@rules $ctx:tt) => {};
+
+    (@rules $ctx:tt $name:ident : $ty:ty = $($rest:tt)*) => {
+        $crate::grammar!(@rule $ctx $name [$ty] [] $($rest)*);
+    };
+
+    (@rule [$input:ty; $($lift:tt)*] $name:ident [$ty:ty] [$($body:tt)*] ; $($rest:tt)*) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name {}
+
+        #[allow(dead_code)]
+        pub fn $name() -> $name { $name {} }
+
+        impl<'r, 'i> $crate::Rule<'r, $input> for $name {
+            type Mat = $ty;
+            type Exp = $crate::Failed<'static>;
+
+            fn rule(&'r self, input: $input) -> $crate::Ruled<$input, Self::Mat, Self::Exp> {
+                #[allow(unused_imports)]
+                use $crate::Rule as _;
+
+                let rule = $crate::grammar!(@alt [$input; $($lift)*] [] [] $($body)*);
+                $crate::Rule::rule(&rule, input)
+            }
+        }
+
+        $crate::grammar!(@rules [$input; $($lift)*] $($rest)*);
+    };
+
+    // The arms ending with `;` ensure none of the first four tokens is `;`,
+    // so the last arm moves them at once to keep the recursion shallow
+    (@rule $ctx:tt $name:ident [$ty:ty] [$($body:tt)*] $a:tt ; $($rest:tt)*) => {
+        $crate::grammar!(@rule $ctx $name [$ty] [$($body)* $a] ; $($rest)*);
+    };
+
+    (@rule $ctx:tt $name:ident [$ty:ty] [$($body:tt)*] $a:tt $b:tt ; $($rest:tt)*) => {
+        $crate::grammar!(@rule $ctx $name [$ty] [$($body)* $a $b] ; $($rest)*);
+    };
+
+    (@rule $ctx:tt $name:ident [$ty:ty] [$($body:tt)*] $a:tt $b:tt $c:tt ; $($rest:tt)*) => {
+        $crate::grammar!(@rule $ctx $name [$ty] [$($body)* $a $b $c] ; $($rest)*);
+    };
+
+    (@rule $ctx:tt $name:ident [$ty:ty] [$($body:tt)*] $a:tt $b:tt $c:tt $d:tt $($rest:tt)*) => {
+        $crate::grammar!(@rule $ctx $name [$ty] [$($body)* $a $b $c $d] $($rest)*);
+    };
+
+    // Splits alternatives by `/`
+    (@alt $ctx:tt [$($alts:tt)*] [$($cur:tt)*]) => {
+        $crate::grammar!(@or $ctx $($alts)* [$($cur)*])
+    };
+
+    (@alt $ctx:tt [$($alts:tt)*] [$($cur:tt)*] / $($rest:tt)*) => {
+        $crate::grammar!(@alt $ctx [$($alts)* [$($cur)*]] [] $($rest)*)
+    };
+
+    (@alt $ctx:tt [$($alts:tt)*] [$($cur:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::grammar!(@alt $ctx [$($alts)*] [$($cur)* $t] $($rest)*)
+    };
+
+    (@or $ctx:tt [$($alt:tt)*]) => {
+        $crate::grammar!(@action $ctx [] $($alt)*)
+    };
+
+    (@or $ctx:tt [$($alt:tt)*] $($rest:tt)+) => {
+        $crate::compound::Or(
+            $crate::grammar!(@action $ctx [] $($alt)*),
+            $crate::grammar!(@or $ctx $($rest)+),
+        )
+    };
+
+    // Splits the action by `=>`
+    (@action $ctx:tt [$($seq:tt)*]) => {
+        $crate::grammar!(@seq $ctx [] [] $($seq)*)
+    };
+
+    (@action [$input:ty; $($lift:tt)*] [$($seq:tt)*] => $($action:tt)*) => {
+        $crate::Rule::<$input>::map($crate::grammar!(@seq [$input; $($lift)*] [] [] $($seq)*), $($action)*)
+    };
+
+    (@action $ctx:tt [$($seq:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::grammar!(@action $ctx [$($seq)* $t] $($rest)*)
+    };
+
+    // Parses items of the sequence with their prefixes
+    (@seq $ctx:tt [$item:tt] []) => { $item };
+
+    (@seq $ctx:tt [$($items:tt)*] []) => { ($($items),*) };
+
+    (@seq $ctx:tt [$($items:tt)*] [$($pre:tt)*] ! $($rest:tt)*) => {
+        $crate::grammar!(@seq $ctx [$($items)*] [! $($pre)*] $($rest)*)
+    };
+
+    (@seq $ctx:tt [$($items:tt)*] [$($pre:tt)*] & $($rest:tt)*) => {
+        $crate::grammar!(@seq $ctx [$($items)*] [& $($pre)*] $($rest)*)
+    };
+
+    (@seq [$input:ty; $($lift:tt)*] [$($items:tt)*] [$($pre:tt)*] $lit:literal $($rest:tt)*) => {
+        $crate::grammar!(@post [$input; $($lift)*] [$($items)*] [$($pre)*]
+            ($($lift)*($crate::Rule::map_exp($lit, |_| $crate::Failed::from($lit)))) $($rest)*)
+    };
+
+    (@seq $ctx:tt [$($items:tt)*] [$($pre:tt)*] $name:ident $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*] ($name {}) $($rest)*)
+    };
+
+    (@seq $ctx:tt [$($items:tt)*] [$($pre:tt)*] { $($rule:tt)* } $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*] ({ $($rule)* }) $($rest)*)
+    };
+
+    (@seq $ctx:tt [$($items:tt)*] [$($pre:tt)*] ( $($group:tt)* ) $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*]
+            ($crate::grammar!(@alt $ctx [] [] $($group)*)) $($rest)*)
+    };
+
+    // Applies suffixes and prefixes to the item
+    (@post $ctx:tt [$($items:tt)*] [$($pre:tt)*] $item:tt * $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*]
+            ($crate::Rule::range::<::std::vec::Vec<_>, _>($item, ..)) $($rest)*)
+    };
+
+    (@post $ctx:tt [$($items:tt)*] [$($pre:tt)*] $item:tt + $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*]
+            ($crate::Rule::range::<::std::vec::Vec<_>, _>($item, 1..)) $($rest)*)
+    };
+
+    (@post $ctx:tt [$($items:tt)*] [$($pre:tt)*] $item:tt ? $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*] ($crate::compound::Opt($item)) $($rest)*)
+    };
+
+    (@post $ctx:tt [$($items:tt)*] [! $($pre:tt)*] $item:tt $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*]
+            ($crate::Rule::map_exp($crate::compound::NotFollowedBy($item), |e| match e {
+                $crate::Failed::Unexpected(len) => $crate::Failed::Unexpected(len),
+                _ => $crate::Failed::Nothing,
+            })) $($rest)*)
+    };
+
+    (@post $ctx:tt [$($items:tt)*] [& $($pre:tt)*] $item:tt $($rest:tt)*) => {
+        $crate::grammar!(@post $ctx [$($items)*] [$($pre)*] ($crate::compound::Peek($item)) $($rest)*)
+    };
+
+    (@post $ctx:tt [$($items:tt)*] [] $item:tt $($rest:tt)*) => {
+        $crate::grammar!(@seq $ctx [$($items)* $item] [] $($rest)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        basic::latin,
+        compound::lift,
+        State,
+    };
+
+    #[derive(Debug, PartialEq)]
+    pub enum Value {
+        Nil,
+        Num(i64),
+        List(Vec<Value>),
+    }
+
+    grammar! {
+        mod list;
+
+        value: Value = "nil" !letter => |_| Value::Nil
+            / number => Value::Num
+            / '[' items? ']' => |(_, items, _)| Value::List(items.unwrap_or_default());
+        items: Vec<Value> = value (',' value)* => |(first, rest)| {
+            let mut items = vec![first];
+            items.extend(rest.into_iter().map(|(_, v)| v));
+            items
+        };
+        number: i64 = &('-'? digit) { crate::basic::int() } => |(_, n)| n;
+        letter: &'i str = { crate::basic::latin() };
+        digit: &'i str = { crate::basic::dec() };
+    }
+
+    #[test]
+    fn grammar() {
+        let r = list::value();
+        assert_eq!(r.rule("[nil,[1,-2],[]]"), Match(Value::List(vec![
+            Value::Nil,
+            Value::List(vec![Value::Num(1), Value::Num(-2)]),
+            Value::List(vec![]),
+        ]), ""));
+        assert_eq!(r.rule("nil!"), Match(Value::Nil, "!"));
+        assert_eq!(r.rule("nile"), Expected(Failed::Char('[')));
+        assert_eq!(r.rule("[1,]"), Expected(Failed::Char(']')));
+    }
+
+    grammar! {
+        mod declared<Vec<String>>;
+
+        decls: () = decl* => |_| ();
+        decl: () = "let " name ';' => |_| ();
+        name: () = { lift(latin()).update(|names: &mut Vec<String>, n: &&str| names.push(n.to_string())) } => |_| ();
+    }
+
+    #[test]
+    fn stateful() {
+        let state = State::new(vec![]);
+
+        // The last declaration fails after its name is added, so the name is dropped
+        match declared::decls().rule(state.input("let a;let b;let c")) {
+            Match((), rest) => {
+                assert_eq!(rest.input(), "let c");
+                assert_eq!(rest.with(|names| names.clone()), ["a", "b"]);
+            }
+            Expected(_) => panic!("The rule is expected to match"),
+        }
+
+        assert_eq!(declared::decl().rule(state.input("let 1;")).exp(), Some(Failed::Latin));
+    }
+}
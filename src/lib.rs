@@ -8,25 +8,47 @@
 //! [basic]: ./basic/index.html
 //! [compound]: ./compound/index.html
 
+extern crate self as parsers;
+
 #[macro_use]
 mod macros;
 
+mod case_fold;
 mod char_range;
+mod char_set;
 mod concat;
+mod consumed;
 mod failed;
+mod grammar;
 mod into_rule;
 mod is_empty;
+mod no_case;
+mod parse;
+mod parse_tree;
 mod rule;
 mod ruled;
+mod scan;
+mod span;
+mod state;
 mod tuple_impl;
 
 pub use char_range::*;
+pub use char_set::*;
 pub use concat::*;
+pub use consumed::*;
 pub use failed::Failed;
 pub use into_rule::*;
 pub use is_empty::*;
+pub use no_case::*;
+pub use parse::Parse;
+pub use parse_tree::{Children, ParseTree, ParseTreeIter};
+#[cfg(feature = "derive")]
+pub use parsers_derive::Parse;
 pub use rule::*;
 pub use ruled::*;
+pub use scan::*;
+pub use span::*;
+pub use state::*;
 
 pub mod prelude {
     #[doc(no_inline)]
@@ -36,71 +58,140 @@ pub mod prelude {
         Failed,
         compound::rul,
         char_range::char_range,
+        char_set::char_set,
+        no_case::no_case,
     };
 }
 
+/// Grammars loaded from PEG or EBNF text at runtime.
+pub mod dynamic {
+    mod expr;
+    mod grammar;
+    mod syntax;
+
+    pub use expr::Expr;
+    pub use grammar::{Grammar, GrammarError, GrammarRule};
+}
+
 /// Basic rules such as numbers, letters and spaces.
 pub mod basic {
+    mod alphabetic;
+    mod alphanumeric;
     mod any;
     mod bin;
+    mod control;
     mod dec;
+    mod float;
     mod hex;
+    mod int;
     mod latin;
+    mod lowercase;
     mod nl;
+    mod numeric;
     mod oct;
+    mod quoted;
     mod space;
+    mod unicode_white;
+    mod uppercase;
     mod white;
     mod whites;
+    mod xid_continue;
+    mod xid_start;
 
+    pub use alphabetic::{alphabetic, Alphabetic};
+    pub use alphanumeric::{alphanumeric, Alphanumeric};
     pub use any::{any, Any};
     pub use bin::{bin, Bin};
+    pub use control::{control, Control};
     pub use dec::{dec, Dec};
+    pub use float::{float, Float, FloatNumber};
     pub use hex::{hex, Hex};
+    pub use int::{int, prefixed_int, radix_int, uint, Int, Integer};
     pub use latin::{latin, Latin};
+    pub use lowercase::{lowercase, Lowercase};
     pub use nl::{nl, Nl};
+    pub use numeric::{numeric, Numeric};
     pub use oct::{oct, Oct};
+    pub use quoted::{quoted, Quoted, Quoting};
     pub use space::{space, Space};
+    pub use unicode_white::{unicode_white, UnicodeWhite};
+    pub use uppercase::{uppercase, Uppercase};
     pub use white::{white, White};
     pub use whites::{whites, Whites};
+    pub use xid_continue::{xid_continue, XidContinue};
+    pub use xid_start::{xid_start, XidStart};
 }
 
 /// The compound rules to build complex rules.
 pub mod compound {
     mod cat;
     mod end;
+    mod except;
     mod filter;
     mod fst;
+    mod indent;
+    mod keyword;
+    mod keywords;
+    mod longest;
+    mod lookahead;
     mod map;
     mod map_exp;
+    mod node;
     mod not;
     mod one_of;
     mod opt;
     mod or;
     mod or_default;
+    mod parsed;
+    mod permutation;
     mod range;
+    mod recognize;
     mod ret;
     mod ret_exp;
     mod rul;
     mod snd;
+    mod stateful;
+    mod take_until;
+    mod take_while;
     mod to;
     mod until;
+    mod with_span;
 
     pub use cat::*;
     pub use end::*;
+    pub use except::*;
     pub use filter::*;
     pub use fst::*;
+    pub use indent::*;
+    pub use keyword::{keyword, Keyword};
+    pub use keywords::{keywords, Keywords};
+    pub use longest::{longest, longest_of, Longest, LongestOf};
+    pub use lookahead::{not_followed_by, peek, NotFollowedBy, Peek};
     pub use map::*;
     pub use map_exp::*;
+    pub use node::Node;
     pub use not::*;
     pub use one_of::{one_of, OneOf};
     pub use opt::*;
     pub use or::*;
     pub use or_default::*;
+    pub use parsed::{parse, Parsed};
+    pub use permutation::{permutation, Permutation};
     pub use range::*;
+    pub use recognize::*;
     pub use ret::{ret, Ret};
     pub use ret_exp::{ret_exp, RetExp};
     pub use rul::{rul, Rul};
     pub use snd::*;
+    pub use stateful::*;
+    pub use take_until::*;
+    pub use take_while::*;
     pub use to::*;
     pub use until::*;
+    pub use with_span::*;
+}
+
+#[cfg(test)]
+mod tests {
+    mod json;
 }
@@ -15,6 +15,7 @@ macro_rules! impl_ops {
         impl_or!($t< $($g),+ >);
         impl_shifts!($t< $($g),+ >);
         impl_not!($t< $($g),+ >);
+        impl_sub!($t< $($g),+ >);
     };
 
     ($t:ident) => {
@@ -33,6 +34,7 @@ macro_rules! impl_ops {
         impl_or!($t);
         impl_shifts!($t);
         impl_not!($t);
+        impl_sub!($t);
     };
 }
 
@@ -101,3 +103,21 @@ macro_rules! impl_not {
         }
     };
 }
+
+macro_rules! impl_sub {
+    ($t:ident< $($g:tt),+ >) => {
+        impl< $($g),+ , T> std::ops::Sub<T> for $t< $($g),+ > {
+            type Output = crate::compound::Except<$t< $($g),+ >, T>;
+
+            fn sub(self, rhs: T) -> Self::Output { crate::compound::Except(self, rhs) }
+        }
+    };
+
+    ($t:ident) => {
+        impl<T> std::ops::Sub<T> for $t {
+            type Output = crate::compound::Except<$t, T>;
+
+            fn sub(self, rhs: T) -> Self::Output { crate::compound::Except(self, rhs) }
+        }
+    };
+}
@@ -0,0 +1,125 @@
+use crate::{
+    prelude::*,
+    case_fold::fold_case,
+};
+
+/// Case-insensitive literal parser.
+///
+/// Matches a `char` or a string ignoring the case of letters.
+/// Chars are compared using Unicode simple case folding,
+/// so the match always has the same number of chars as the literal.
+/// Returns the slice of the input in its original case.
+/// Use [`no_case`] to create the rule.
+///
+/// [`no_case`]: ./fn.no_case.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::prelude::*;
+/// let rule = no_case("select");
+///
+/// assert_eq!(rule.rule("SeLeCt *"), Match("SeLeCt", " *"));
+/// assert!(rule.rule("update").is_expected());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct NoCase<T>(pub T);
+
+/// [`NoCase`] constructor. See [`NoCase`] for details.
+///
+/// [`NoCase`]: ./struct.NoCase.html
+pub fn no_case<T>(literal: T) -> NoCase<T> { NoCase(literal) }
+
+/// Returns the length of the input prefix matching the pattern ignoring the case.
+fn match_no_case(pattern: &str, input: &str) -> Option<usize> {
+    let mut chars = input.char_indices();
+
+    for p in pattern.chars() {
+        match chars.next() {
+            Some((_, c)) if c == p || fold_case(c) == fold_case(p) => {}
+            _ => return None,
+        }
+    }
+
+    Some(chars.next().map_or(input.len(), |(i, _)| i))
+}
+
+impl<'r, 'i> Rule<'r, &'i str> for NoCase<char> {
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        let mut buf = [0; 4];
+
+        match match_no_case(self.0.encode_utf8(&mut buf), input) {
+            Some(len) => input.split_at(len).into(),
+            None => Expected(Failed::CharNoCase(self.0)),
+        }
+    }
+}
+
+impl<'r, 'i, 'a: 'r> Rule<'r, &'i str> for NoCase<&'a str> {
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match match_no_case(self.0, input) {
+            Some(len) => input.split_at(len).into(),
+            None => Expected(Failed::StrNoCase(self.0)),
+        }
+    }
+}
+
+impl<'r, 'i> Rule<'r, &'i str> for NoCase<String> {
+    type Mat = &'i str;
+    type Exp = Failed<'r>;
+
+    fn rule(&'r self, input: &'i str) -> Ruled<&'i str, Self::Mat, Self::Exp> {
+        match match_no_case(&self.0, input) {
+            Some(len) => input.split_at(len).into(),
+            None => Expected(Failed::StrNoCase(&self.0)),
+        }
+    }
+}
+
+impl_ops!(NoCase<L>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_case_char() {
+        let r = super::no_case('x');
+        assert_eq!(r.rule("xy"), Match("x", "y"));
+        assert_eq!(r.rule("Xy"), Match("X", "y"));
+        assert_eq!(r.rule("y"), Expected(Failed::CharNoCase('x')));
+
+        let r = super::no_case('σ');
+        assert_eq!(r.rule("Σ"), Match("Σ", ""));
+        assert_eq!(r.rule("ς"), Match("ς", ""));
+
+        // The dotless `ı` has no simple folding, it only uppercases to `I`
+        let r = super::no_case('i');
+        assert_eq!(r.rule("I"), Match("I", ""));
+        assert_eq!(r.rule("ı"), Expected(Failed::CharNoCase('i')));
+        assert_eq!(super::no_case("limit").rule("lımıt"), Expected(Failed::StrNoCase("limit")));
+    }
+
+    #[test]
+    fn no_case_str() {
+        let r = super::no_case("select");
+        assert_eq!(r.rule("select"), Match("select", ""));
+        assert_eq!(r.rule("SELECT *"), Match("SELECT", " *"));
+        assert_eq!(r.rule("SELEC"), Expected(Failed::StrNoCase("select")));
+        assert_eq!(r.rule("insert"), Expected(Failed::StrNoCase("select")));
+
+        // The Kelvin sign is longer than `k` in UTF-8
+        let r = super::no_case("ok");
+        assert_eq!(r.rule("O\u{212A}!"), Match("O\u{212A}", "!"));
+
+        let r = super::no_case("straße".to_owned());
+        assert_eq!(r.rule("STRAẞE"), Match("STRAẞE", ""));
+        assert_eq!(r.rule("STRASSE"), Expected(Failed::StrNoCase("straße")));
+    }
+}
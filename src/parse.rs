@@ -0,0 +1,200 @@
+use crate::{
+    prelude::*,
+    basic::{float, int},
+};
+
+/// An interface of types parsed from a string.
+///
+/// Implement it with `#[derive(Parse)]` of the `derive` feature,
+/// and use [`parse`] to get the rule of the type.
+/// It's implemented for integers, floats, `bool`, `char`,
+/// and `Box`, `Option` and `Vec` of parsed types.
+///
+/// [`parse`]: ./compound/fn.parse.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, Parse};
+/// assert_eq!(<Vec<bool>>::parse("truefalse!"), Match(vec![true, false], "!"));
+/// assert_eq!(<Option<u8>>::parse("x"), Match(None, "x"));
+/// ```
+pub trait Parse<'i>: Sized {
+    /// Parses the value from the start of the input.
+    fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>>;
+}
+
+macro_rules! impl_parse {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl<'i> Parse<'i> for $t {
+                fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> { int().rule(input) }
+            }
+        )+
+    };
+}
+
+impl_parse!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<'i> Parse<'i> for f32 {
+    fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> { float().rule(input).map(|(f, _)| f) }
+}
+
+impl<'i> Parse<'i> for f64 {
+    fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> { float().rule(input).map(|(f, _)| f) }
+}
+
+impl<'i> Parse<'i> for bool {
+    fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> {
+        static TRUE: &str = "true";
+        static FALSE: &str = "false";
+
+        TRUE.rule(input).map(|_| true)
+            .or_else(|_| FALSE.rule(input).map(|_| false))
+    }
+}
+
+impl<'i> Parse<'i> for char {
+    fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> {
+        match input.chars().next() {
+            Some(c) => Match(c, &input[c.len_utf8()..]),
+            None => Expected(Failed::AnyChar),
+        }
+    }
+}
+
+impl<'i, P> Parse<'i> for Box<P>
+    where
+        P: Parse<'i>,
+{
+    fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> { P::parse(input).map(Box::new) }
+}
+
+impl<'i, P> Parse<'i> for Option<P>
+    where
+        P: Parse<'i>,
+{
+    fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> {
+        match P::parse(input) {
+            Match(m, rest) => Match(Some(m), rest),
+            Expected(_) => Match(None, input),
+        }
+    }
+}
+
+/// Parses values while they match and consume the input.
+impl<'i, P> Parse<'i> for Vec<P>
+    where
+        P: Parse<'i>,
+{
+    fn parse(mut input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> {
+        let mut items = Vec::new();
+
+        while let Match(m, rest) = P::parse(input) {
+            if rest.len() == input.len() {
+                break;
+            }
+
+            input = rest;
+            items.push(m);
+        }
+
+        Match(items, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compound::parse;
+    use parsers_derive::Parse;
+
+    #[derive(Debug, PartialEq, Parse)]
+    #[parse(skip_white)]
+    enum Expr {
+        #[parse(literal = "(", suffix = ")")]
+        Call(Name, #[parse(sep = ",")] Vec<Expr>),
+        Num(f64),
+        Neg(#[parse(literal = "-")] Box<Expr>),
+        #[parse(literal = "nil")]
+        Nil,
+    }
+
+    #[derive(Debug, PartialEq, Parse)]
+    struct Name(char, Vec<Lower>);
+
+    #[derive(Debug, PartialEq)]
+    struct Lower(char);
+
+    impl<'i> Parse<'i> for Lower {
+        fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> {
+            char::parse(input)
+                .and_then(|c, rest| if c.is_ascii_lowercase() {
+                    Match(Lower(c), rest)
+                } else {
+                    Expected(Failed::Predicate)
+                })
+        }
+    }
+
+    #[derive(Debug, PartialEq, Parse)]
+    struct Pair<'i, P> {
+        #[parse(literal = "<")]
+        first: P,
+        #[parse(literal = ",", skip_white)]
+        second: Option<P>,
+        #[parse(sep = ";", suffix = ">")]
+        words: Vec<Word<'i>>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Word<'i>(&'i str);
+
+    impl<'i> Parse<'i> for Word<'i> {
+        fn parse(input: &'i str) -> Ruled<&'i str, Self, Failed<'static>> {
+            let len = input.find(|c: char| !c.is_ascii_lowercase()).unwrap_or(input.len());
+
+            if len == 0 {
+                Expected(Failed::Predicate)
+            } else {
+                Match(Word(&input[..len]), &input[len..])
+            }
+        }
+    }
+
+    fn name(s: &str) -> Name {
+        let mut chars = s.chars();
+        Name(chars.next().unwrap(), chars.map(Lower).collect())
+    }
+
+    #[test]
+    fn primitives() {
+        assert_eq!(i32::parse("-12x"), Match(-12, "x"));
+        assert_eq!(f64::parse("1.5e1"), Match(15.0, ""));
+        assert_eq!(bool::parse("maybe"), Expected(Failed::Str("false")));
+        assert_eq!(char::parse(""), Expected(Failed::AnyChar));
+        assert_eq!(<Vec<Option<u8>>>::parse("1"), Match(vec![Some(1)], ""));
+    }
+
+    #[test]
+    fn derive() {
+        let r = parse::<Expr>();
+        assert_eq!(r.rule(" ( max 1, - 2.5,nil ) !"), Match(Expr::Call(name("max"), vec![
+            Expr::Num(1.0),
+            Expr::Neg(Box::new(Expr::Num(2.5))),
+            Expr::Nil,
+        ]), " !"));
+        assert_eq!(r.rule("(f)"), Match(Expr::Call(name("f"), vec![]), ""));
+        assert_eq!(r.rule("(f 1,)"), Expected(Failed::Str("nil")));
+        assert_eq!(r.rule("nul"), Expected(Failed::Str("nil")));
+
+        let r = parse::<Pair<u8>>();
+        assert_eq!(r.rule("<1, 2a;b>"), Match(Pair {
+            first: 1,
+            second: Some(2),
+            words: vec![Word("a"), Word("b")],
+        }, ""));
+        assert_eq!(r.rule("<1,>"), Match(Pair { first: 1, second: None, words: vec![] }, ""));
+        assert_eq!(r.rule("<1,a;>"), Expected(Failed::Str(">")));
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    borrow::Cow,
+    fmt,
+};
+
+use crate::{
+    Count,
+    Span,
+};
+
+/// A node of an untyped parse result.
+///
+/// Holds the name of the rule that produced the node, the [`Span`] and the text
+/// of the input consumed by the rule, and the nodes of the rules applied inside it.
+/// The text of a leaf is usually what the node stands for.
+///
+/// Nodes are produced by [`Rule::node`] and dynamic [grammars].
+/// `Display` prints the tree with indentation, [`to_sexp`] as an S-expression.
+///
+/// [`Span`]: ./struct.Span.html
+/// [`Rule::node`]: ./trait.Rule.html#method.node
+/// [grammars]: ./dynamic/struct.Grammar.html
+/// [`to_sexp`]: ./struct.ParseTree.html#method.to_sexp
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::prelude::*;
+/// let num = char_range('0'..='9').skip_many1().recognize().node("num");
+/// let sum = (&num, ('+', &num).range::<Vec<_>, _>(..)).node("sum");
+///
+/// let tree = sum.rule("1+23").mat().unwrap();
+/// assert_eq!(tree.to_sexp(), r#"(sum (num "1") (num "23"))"#);
+/// assert_eq!(tree.to_string(), "sum\n  num \"1\"\n  num \"23\"\n");
+/// assert_eq!(tree.iter().filter(|n| n.is_leaf()).map(|n| n.text).collect::<Vec<_>>(), ["1", "23"]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseTree<'n, 'i> {
+    /// The name of the rule.
+    pub name: &'n str,
+
+    /// The span of the consumed input.
+    pub span: Span,
+
+    /// The consumed input.
+    pub text: &'i str,
+
+    /// The nodes of the nested rules in the input order.
+    pub children: Vec<ParseTree<'n, 'i>>,
+}
+
+impl<'n, 'i> ParseTree<'n, 'i> {
+    /// Returns `true` if the node has no children.
+    pub fn is_leaf(&self) -> bool { self.children.is_empty() }
+
+    /// Returns an iterator over the node and all its descendants in depth-first order.
+    pub fn iter(&self) -> ParseTreeIter<'_, 'n, 'i> { ParseTreeIter { stack: vec![self] } }
+
+    /// Calls the function for the node and all its descendants in depth-first order
+    /// with their depth, which is `0` for this node.
+    pub fn walk<F>(&self, mut f: F)
+        where
+            F: FnMut(&ParseTree<'n, 'i>, usize),
+    {
+        fn walk<'n, 'i, F>(node: &ParseTree<'n, 'i>, depth: usize, f: &mut F)
+            where
+                F: FnMut(&ParseTree<'n, 'i>, usize),
+        {
+            f(node, depth);
+            node.children.iter().for_each(|c| walk(c, depth + 1, f));
+        }
+
+        walk(self, 0, &mut f)
+    }
+
+    /// Returns the tree as an S-expression.
+    ///
+    /// A node is written as `(name children...)`, a leaf as `(name "text")`.
+    /// Names are written as is, and only `"` and `\` are escaped in the text,
+    /// other chars including line breaks are kept.
+    pub fn to_sexp(&self) -> String {
+        let mut out = String::new();
+        self.write_sexp(&mut out);
+        out
+    }
+
+    fn write_sexp(&self, out: &mut String) {
+        out.push('(');
+        out.push_str(self.name);
+
+        if self.is_leaf() {
+            out.push_str(" \"");
+
+            for c in self.text.chars() {
+                if c == '"' || c == '\\' {
+                    out.push('\\');
+                }
+
+                out.push(c);
+            }
+
+            out.push('"');
+        }
+
+        for child in &self.children {
+            out.push(' ');
+            child.write_sexp(out);
+        }
+
+        out.push(')');
+    }
+}
+
+/// Writes a node per line indented by two spaces per level, leaves with their text.
+impl fmt::Display for ParseTree<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = Ok(());
+
+        self.walk(|node, depth| {
+            res = res.and_then(|_| match node.is_leaf() {
+                true => writeln!(f, "{:indent$}{} {:?}", "", node.name, node.text, indent = depth * 2),
+                false => writeln!(f, "{:indent$}{}", "", node.name, indent = depth * 2),
+            });
+        });
+
+        res
+    }
+}
+
+impl<'a, 'n, 'i> IntoIterator for &'a ParseTree<'n, 'i> {
+    type Item = &'a ParseTree<'n, 'i>;
+    type IntoIter = ParseTreeIter<'a, 'n, 'i>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// An iterator over nodes of a [`ParseTree`] in depth-first order.
+///
+/// [`ParseTree`]: ./struct.ParseTree.html
+#[derive(Clone, Debug)]
+pub struct ParseTreeIter<'a, 'n, 'i> {
+    stack: Vec<&'a ParseTree<'n, 'i>>,
+}
+
+impl<'a, 'n, 'i> Iterator for ParseTreeIter<'a, 'n, 'i> {
+    type Item = &'a ParseTree<'n, 'i>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children.iter().rev());
+        Some(node)
+    }
+}
+
+/// An interface of matches containing [`ParseTree`] nodes.
+///
+/// [`Rule::node`] uses it to collect the children of the node from the match of the rule.
+/// Strings, chars, numbers, spans and the other matches of basic rules contain no nodes.
+///
+/// [`ParseTree`]: ./struct.ParseTree.html
+/// [`Rule::node`]: ./trait.Rule.html#method.node
+pub trait Children<'n, 'i> {
+    /// Appends the nodes to the vector in the input order.
+    fn collect_into(self, nodes: &mut Vec<ParseTree<'n, 'i>>);
+}
+
+impl<'n, 'i> Children<'n, 'i> for ParseTree<'n, 'i> {
+    fn collect_into(self, nodes: &mut Vec<ParseTree<'n, 'i>>) { nodes.push(self) }
+}
+
+macro_rules! impl_leaf {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl<'n, 'i> Children<'n, 'i> for $t {
+                fn collect_into(self, _: &mut Vec<ParseTree<'n, 'i>>) {}
+            }
+        )+
+    };
+}
+
+impl_leaf!((), &str, String, Cow<'_, str>, char, bool, Span, Count);
+impl_leaf!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<'n, 'i, T> Children<'n, 'i> for Option<T>
+    where
+        T: Children<'n, 'i>,
+{
+    fn collect_into(self, nodes: &mut Vec<ParseTree<'n, 'i>>) {
+        if let Some(m) = self {
+            m.collect_into(nodes);
+        }
+    }
+}
+
+impl<'n, 'i, T> Children<'n, 'i> for Vec<T>
+    where
+        T: Children<'n, 'i>,
+{
+    fn collect_into(self, nodes: &mut Vec<ParseTree<'n, 'i>>) {
+        self.into_iter().for_each(|m| m.collect_into(nodes));
+    }
+}
+
+macro_rules! impl_children {
+    ($($t:ident),+) => {
+        impl<'n, 'i, $($t),+> Children<'n, 'i> for ($($t,)+)
+            where
+                $($t: Children<'n, 'i>),+
+        {
+            #[allow(non_snake_case)]
+            fn collect_into(self, nodes: &mut Vec<ParseTree<'n, 'i>>) {
+                let ($($t,)+) = self;
+                $($t.collect_into(nodes);)+
+            }
+        }
+    };
+}
+
+impl_children!(M0);
+impl_children!(M0, M1);
+impl_children!(M0, M1, M2);
+impl_children!(M0, M1, M2, M3);
+impl_children!(M0, M1, M2, M3, M4);
+impl_children!(M0, M1, M2, M3, M4, M5);
+impl_children!(M0, M1, M2, M3, M4, M5, M6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node<'i>(name: &'static str, src: &'i str, text: &'i str, children: Vec<ParseTree<'static, 'i>>) -> ParseTree<'static, 'i> {
+        let start = src.find(text).unwrap();
+        let span = Span::new(&src[start..], &src[start + text.len()..]);
+
+        ParseTree { name, span, text, children }
+    }
+
+    #[test]
+    fn parse_tree() {
+        let src = "f(x, \"y\")";
+        let tree = node("call", src, src, vec![
+            node("name", src, "f", vec![]),
+            node("args", src, "x, \"y\"", vec![
+                node("var", src, "x", vec![]),
+                node("str", src, "\"y\"", vec![]),
+            ]),
+        ]);
+
+        assert_eq!(tree.iter().map(|n| n.name).collect::<Vec<_>>(), ["call", "name", "args", "var", "str"]);
+        assert_eq!((&tree).into_iter().filter(|n| n.is_leaf()).count(), 3);
+
+        let mut depths = Vec::new();
+        tree.walk(|n, depth| depths.push((n.name, depth)));
+        assert_eq!(depths, [("call", 0), ("name", 1), ("args", 1), ("var", 2), ("str", 2)]);
+
+        assert_eq!(tree.to_sexp(), r#"(call (name "f") (args (var "x") (str "\"y\"")))"#);
+        assert_eq!(tree.to_string(), [
+            "call",
+            "  name \"f\"",
+            "  args",
+            "    var \"x\"",
+            "    str \"\\\"y\\\"\"",
+            "",
+        ].join("\n"));
+        assert_eq!(tree.children[1].span.range(src), 2..8);
+
+        let src = "a\\\n";
+        assert_eq!(node("s", src, src, vec![]).to_sexp(), "(s \"a\\\\\n\")");
+    }
+
+    #[test]
+    fn children() {
+        let src = "ab";
+        let (a, b) = (node("a", src, "a", vec![]), node("b", src, "b", vec![]));
+
+        let mut nodes = Vec::new();
+        ("x", (Some(a.clone()), 'c'), vec![b.clone()], (), None::<ParseTree>).collect_into(&mut nodes);
+        assert_eq!(nodes, [a, b]);
+
+        let mut nodes = Vec::new();
+        (1usize, (2.5, String::new()), Cow::Borrowed("y"), Span::new(src, src)).collect_into(&mut nodes);
+        assert_eq!(nodes, []);
+    }
+}
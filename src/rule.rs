@@ -1,7 +1,11 @@
 use super::{
     prelude::*,
     Concat,
+    Consumed,
+    Count,
+    HasState,
     IsEmpty,
+    Span,
     compound::*,
 };
 
@@ -15,10 +19,7 @@ pub trait Rule<'r, I> {
         where
             I: IsEmpty,
     {
-        match self.rule(input) {
-            Match(_, i) if i.is_empty() => true,
-            _ => false,
-        }
+        matches!(self.rule(input), Match(_, i) if i.is_empty())
     }
 
     fn cat<C, R>(self, rhs: R) -> Cat<Self, R, C>
@@ -75,19 +76,26 @@ pub trait Rule<'r, I> {
 
     fn not(self) -> Not<Self>
         where
-            I: Copy,
+            I: Clone,
             Self: Sized,
     { Not(self) }
 
+    fn except<R>(self, rhs: R) -> Except<Self, R>
+        where
+            R: Rule<'r, I>,
+            I: Consumed,
+            Self: Sized,
+    { Except(self, rhs) }
+
     fn opt(self) -> Opt<Self>
         where
-            I: Copy,
+            I: Clone,
             Self: Sized,
     { Opt(self) }
 
     fn or_default(self) -> OrDefault<Self>
         where
-            I: Copy,
+            I: Clone,
             Self::Mat: Default,
             Self: Sized,
     { OrDefault(self) }
@@ -95,26 +103,82 @@ pub trait Rule<'r, I> {
     fn range<C, B>(self, rng: B) -> Range<Self, C>
         where
             B: std::ops::RangeBounds<usize>,
-            I: Copy,
+            I: Clone,
             C: Concat<C, Self::Mat>,
             Self: Sized,
     { Range::from_range(self, rng) }
 
     fn repeat<C>(self, times: usize) -> Range<Self, C>
         where
-            I: Copy,
+            I: Clone,
             C: Concat<C, Self::Mat>,
             Self: Sized,
     { Range::from_range(self, times..=times) }
 
+    fn skip_many(self) -> Range<Self, ()>
+        where
+            I: Clone,
+            Self: Sized,
+    { Range::from_range(self, ..) }
+
+    fn skip_many1(self) -> Range<Self, ()>
+        where
+            I: Clone,
+            Self: Sized,
+    { Range::from_range(self, 1..) }
+
+    fn count(self) -> Map<Range<Self, Count>, fn(Count) -> usize>
+        where
+            I: Clone,
+            Self: Sized,
+    { Map(Range::from_range(self, ..), |Count(n)| n) }
+
     fn until<C, U>(self, until: U) -> Until<Self, U, C>
         where
             U: Rule<'r, I>,
-            I: Copy,
+            I: Clone,
             C: Concat<C, Self::Mat>,
             Self: Sized,
     { Until::new(self, until) }
 
+    fn recognize(self) -> Recognize<Self>
+        where
+            I: Consumed,
+            Self: Sized,
+    { Recognize(self) }
+
+    fn spanned(self) -> WithSpan<Self>
+        where
+            I: Consumed,
+            Self: Sized,
+    { WithSpan(self) }
+
+    fn node(self, name: &str) -> Node<'_, Self>
+        where
+            Self: Sized,
+    { Node(self, name) }
+
+    fn map_with_span<F, K>(self, f: F) -> MapWithSpan<Self, F>
+        where
+            F: Fn(Self::Mat, Span) -> K,
+            I: Consumed,
+            Self: Sized,
+    { MapWithSpan(self, f) }
+
+    fn update<F>(self, f: F) -> Update<Self, F>
+        where
+            I: HasState,
+            F: Fn(&mut I::State, &Self::Mat),
+            Self: Sized,
+    { Update(self, f) }
+
+    fn check<F>(self, f: F) -> Check<Self, F>
+        where
+            I: HasState,
+            F: Fn(&I::State, &Self::Mat) -> bool,
+            Self: Sized,
+    { Check(self, f) }
+
     fn end(self) -> End<Self>
         where
             Self::Exp: Into<Failed<'r>>,
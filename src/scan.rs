@@ -0,0 +1,283 @@
+use std::borrow::Cow;
+
+use crate::prelude::*;
+
+/// A match found by [`Scan`] methods.
+///
+/// Contains the value obtained by the rule
+/// and the byte range of the matched text.
+///
+/// [`Scan`]: ./trait.Scan.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Found<'i, M> {
+    /// The value obtained by the rule.
+    pub value: M,
+
+    /// The byte offset where the match starts.
+    pub start: usize,
+
+    /// The byte offset where the match ends.
+    pub end: usize,
+
+    text: &'i str,
+}
+
+impl<'i, M> Found<'i, M> {
+    /// Returns the byte range of the match.
+    pub fn range(&self) -> std::ops::Range<usize> { self.start..self.end }
+
+    /// Returns the matched text.
+    pub fn as_str(&self) -> &'i str { &self.text[self.start..self.end] }
+
+    /// Returns `true` if the match is empty.
+    pub fn is_empty(&self) -> bool { self.start == self.end }
+
+    /// Converts into the obtained value.
+    pub fn into_value(self) -> M { self.value }
+}
+
+/// Regex-style scanning of a text with [rules].
+///
+/// Unlike [`Rule::test`], which checks the whole input,
+/// these methods search for matches at any position of the text.
+/// The trait is implemented for every rule over `&str` input.
+///
+/// Note that `str` and iterators have their own `find` and `split` methods,
+/// so the trait is not in the prelude and has to be imported explicitly.
+/// Wrap string literals with [`rul`] to call the trait methods.
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::dec, Scan};
+/// let num = dec().range::<&str, _>(1..);
+///
+/// let found = num.find("id: 42").unwrap();
+/// assert_eq!(found.value, "42");
+/// assert_eq!(found.range(), 4..6);
+///
+/// let all: Vec<_> = num.find_iter("1, 22, 333").map(|f| f.value).collect();
+/// assert_eq!(all, ["1", "22", "333"]);
+/// ```
+///
+/// [rules]: ./trait.Rule.html
+/// [`Rule::test`]: ./trait.Rule.html#method.test
+/// [`rul`]: ./compound/fn.rul.html
+pub trait Scan<'r, 'i>: Rule<'r, &'i str> {
+    /// Returns the first match in the text.
+    fn find(&'r self, text: &'i str) -> Option<Found<'i, Self::Mat>> { self.find_at(text, 0) }
+
+    /// Returns the first match in the text starting at the byte offset `start`.
+    ///
+    /// Returns `None` if `start` is past the end of the text or not on a char boundary.
+    fn find_at(&'r self, text: &'i str, start: usize) -> Option<Found<'i, Self::Mat>> {
+        let mut pos = start;
+
+        loop {
+            if let Match(value, rest) = self.rule(text.get(pos..)?) {
+                return Some(Found {
+                    value,
+                    start: pos,
+                    end: text.len() - rest.len(),
+                    text,
+                });
+            }
+
+            pos += text[pos..].chars().next()?.len_utf8();
+        }
+    }
+
+    /// Returns an iterator over all non-overlapping matches in the text.
+    fn find_iter(&'r self, text: &'i str) -> FindIter<'r, 'i, Self> {
+        FindIter {
+            rule: self,
+            text,
+            pos: Some(0),
+            last_end: None,
+        }
+    }
+
+    /// Returns an iterator over substrings of the text separated by matches.
+    fn split(&'r self, text: &'i str) -> Split<'r, 'i, Self> {
+        Split {
+            matches: self.find_iter(text),
+            last: 0,
+            done: false,
+        }
+    }
+
+    /// Replaces all matches in the text with values returned by `f`.
+    ///
+    /// The function receives the value obtained by the rule.
+    /// Returns the borrowed text if there are no matches.
+    fn replace_all<F, S>(&'r self, text: &'i str, f: F) -> Cow<'i, str>
+        where
+            F: FnMut(Self::Mat) -> S,
+            S: AsRef<str>,
+    { self.replace_n(text, usize::MAX, f) }
+
+    /// Replaces at most `n` first matches in the text with values returned by `f`.
+    ///
+    /// The function receives the value obtained by the rule.
+    /// Returns the borrowed text if nothing was replaced.
+    fn replace_n<F, S>(&'r self, text: &'i str, n: usize, mut f: F) -> Cow<'i, str>
+        where
+            F: FnMut(Self::Mat) -> S,
+            S: AsRef<str>,
+    {
+        let mut matches = self.find_iter(text).take(n).peekable();
+        if matches.peek().is_none() {
+            return Cow::Borrowed(text);
+        }
+
+        let mut res = String::with_capacity(text.len());
+        let mut last = 0;
+
+        for found in matches {
+            res.push_str(&text[last..found.start]);
+            res.push_str(f(found.value).as_ref());
+            last = found.end;
+        }
+
+        res.push_str(&text[last..]);
+        Cow::Owned(res)
+    }
+}
+
+impl<'r, 'i, R> Scan<'r, 'i> for R
+    where
+        R: Rule<'r, &'i str> + ?Sized,
+{}
+
+/// An iterator over all non-overlapping matches.
+///
+/// Created by the [`Scan::find_iter`] method.
+///
+/// [`Scan::find_iter`]: ./trait.Scan.html#method.find_iter
+#[derive(Debug)]
+pub struct FindIter<'r, 'i, R: ?Sized> {
+    rule: &'r R,
+    text: &'i str,
+    pos: Option<usize>,
+    last_end: Option<usize>,
+}
+
+impl<'r, 'i, R> Iterator for FindIter<'r, 'i, R>
+    where
+        R: Rule<'r, &'i str> + ?Sized,
+{
+    type Item = Found<'i, R::Mat>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let found = self.rule.find_at(self.text, self.pos?)?;
+
+            self.pos = if found.is_empty() {
+                let next = self.text[found.end..].chars().next();
+                next.map(|c| found.end + c.len_utf8())
+            } else {
+                Some(found.end)
+            };
+
+            // Skip an empty match right after the previous one
+            if found.is_empty() && self.last_end == Some(found.end) {
+                continue;
+            }
+
+            self.last_end = Some(found.end);
+            break Some(found);
+        }
+    }
+}
+
+/// An iterator over substrings separated by matches.
+///
+/// Created by the [`Scan::split`] method.
+///
+/// [`Scan::split`]: ./trait.Scan.html#method.split
+#[derive(Debug)]
+pub struct Split<'r, 'i, R: ?Sized> {
+    matches: FindIter<'r, 'i, R>,
+    last: usize,
+    done: bool,
+}
+
+impl<'r, 'i, R> Iterator for Split<'r, 'i, R>
+    where
+        R: Rule<'r, &'i str> + ?Sized,
+{
+    type Item = &'i str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let text = self.matches.text;
+
+        match self.matches.next() {
+            Some(found) => {
+                let piece = &text[self.last..found.start];
+                self.last = found.end;
+                Some(piece)
+            }
+            None => {
+                self.done = true;
+                Some(&text[self.last..])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::dec;
+
+    #[test]
+    fn find() {
+        let r = dec().range::<&str, _>(1..);
+        assert_eq!(r.find("abc12de").map(|f| (f.value, f.range())), Some(("12", 3..5)));
+        assert_eq!(r.find("abc"), None);
+        assert_eq!(r.find_at("1 2", 1).map(|f| f.start), Some(2));
+        assert_eq!(r.find_at("1 2", 5), None);
+
+        let r = rul('ы');
+        assert_eq!(r.find("шы").map(|f| f.range()), Some(2..4));
+        assert_eq!(r.find_at("шы", 1), None);
+    }
+
+    #[test]
+    fn find_iter() {
+        let r = dec().range::<&str, _>(1..);
+        let all: Vec<_> = r.find_iter("a1b22c333").map(|f| f.as_str()).collect();
+        assert_eq!(all, ["1", "22", "333"]);
+
+        let r = rul('a').range::<&str, _>(..);
+        let all: Vec<_> = r.find_iter("baab").map(|f| f.range()).collect();
+        assert_eq!(all, [0..0, 1..3, 4..4]);
+    }
+
+    #[test]
+    fn split() {
+        let r = (',', ' '.range::<&str, _>(..));
+        let parts: Vec<_> = r.split("a, b,c,").collect();
+        assert_eq!(parts, ["a", "b", "c", ""]);
+
+        let parts: Vec<_> = r.split("").collect();
+        assert_eq!(parts, [""]);
+    }
+
+    #[test]
+    fn replace() {
+        let r = dec().range::<&str, _>(1..).map(|s: &str| s.parse::<i32>().unwrap());
+        let res = r.replace_all("a1 b22 c3", |n| (n * 2).to_string());
+        assert_eq!(res, "a2 b44 c6");
+
+        let res = r.replace_n("a1 b22 c3", 2, |n| (n + 1).to_string());
+        assert_eq!(res, "a2 b23 c3");
+
+        assert!(matches!(r.replace_all("abc", |_| ""), Cow::Borrowed("abc")));
+        assert!(matches!(r.replace_n("a1", 0, |_| ""), Cow::Borrowed("a1")));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::Consumed;
+
+/// A part of the input consumed by a rule.
+///
+/// Rules see only the rest of the input, so the span is stored
+/// as lengths of the input rest at its start and end. Pass the original input
+/// to [`range`], [`slice`] or [`line_col`] to get offsets relative to it.
+///
+/// [`range`]: ./struct.Span.html#method.range
+/// [`slice`]: ./struct.Span.html#method.slice
+/// [`line_col`]: ./struct.Span.html#method.line_col
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, basic::dec};
+/// let src = "x = 42";
+/// let num = dec().skip_many1().spanned();
+///
+/// let span = num.rule(&src[4..]).mat().unwrap().span;
+/// assert_eq!(span.range(src), 4..6);
+/// assert_eq!(span.slice(src), "42");
+/// assert_eq!(span.line_col(src), (1, 5));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Span {
+    start_rest: usize,
+    end_rest: usize,
+}
+
+impl Span {
+    /// Creates the span of the part of `input` that precedes `rest`.
+    pub fn new<I>(input: I, rest: I) -> Self
+        where
+            I: Consumed,
+    {
+        Span {
+            start_rest: input.rest_len(),
+            end_rest: rest.rest_len(),
+        }
+    }
+
+    /// Returns the length of the span.
+    pub fn len(&self) -> usize { self.start_rest - self.end_rest }
+
+    /// Returns `true` if the span is empty.
+    pub fn is_empty(&self) -> bool { self.start_rest == self.end_rest }
+
+    /// Returns the offset where the span starts in the original input.
+    pub fn start<I>(&self, src: I) -> usize
+        where
+            I: Consumed,
+    { src.rest_len() - self.start_rest }
+
+    /// Returns the offset where the span ends in the original input.
+    pub fn end<I>(&self, src: I) -> usize
+        where
+            I: Consumed,
+    { src.rest_len() - self.end_rest }
+
+    /// Returns the range of the span in the original input.
+    pub fn range<I>(&self, src: I) -> std::ops::Range<usize>
+        where
+            I: Consumed,
+    { self.start(src.clone())..self.end(src) }
+
+    /// Returns the part of the original input covered by the span.
+    pub fn slice<I>(&self, src: I) -> I
+        where
+            I: Consumed,
+    { src.clone().suffix(self.start_rest).consumed(src.suffix(self.end_rest)) }
+
+    /// Returns 1-based line and column in chars where the span starts.
+    ///
+    /// Lines are separated by `\n`.
+    pub fn line_col(&self, src: &str) -> (usize, usize) {
+        let before = &src[..self.start(src)];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |n| n + 1);
+
+        (line, before[line_start..].chars().count() + 1)
+    }
+
+    /// Returns the smallest span covering both spans.
+    pub fn join(&self, other: &Span) -> Span {
+        Span {
+            start_rest: self.start_rest.max(other.start_rest),
+            end_rest: self.end_rest.min(other.end_rest),
+        }
+    }
+}
+
+/// A value with the [`Span`] of the input it was obtained from.
+///
+/// [`Span`]: ./struct.Span.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Spanned<M> {
+    /// The value obtained by the rule.
+    pub value: M,
+
+    /// The span of the input consumed by the rule.
+    pub span: Span,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span() {
+        let src = "ab\ncdе\nf";
+        let span = Span::new(&src[4..], &src[7..]);
+
+        assert_eq!(span.len(), 3);
+        assert_eq!(span.range(src), 4..7);
+        assert_eq!(span.slice(src), "dе");
+        assert_eq!(span.line_col(src), (2, 2));
+        assert_eq!(Span::new(&src[8..], &src[9..]).line_col(src), (3, 1));
+
+        let other = Span::new(src, &src[1..]);
+        assert_eq!(span.join(&other).range(src), 0..7);
+    }
+
+    #[test]
+    fn bytes() {
+        let src = b"key=value".as_ref();
+        let span = Span::new(&src[4..], &src[9..]);
+
+        assert_eq!(span.range(src), 4..9);
+        assert_eq!(span.slice(src), b"value");
+    }
+}
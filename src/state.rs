@@ -0,0 +1,164 @@
+use std::rc::Rc;
+
+use crate::{
+    Consumed,
+    IsEmpty,
+};
+
+/// A user state for stateful parsing.
+///
+/// Wrap an input with [`input`] to parse it with rules that read
+/// and modify the state, see [`Rule::update`] and [`Rule::check`].
+/// Stateless rules are applied to the wrapped input with [`lift`].
+///
+/// Every [`Stateful`] input carries a shared snapshot of the state, and
+/// a modification clones the snapshot if other inputs still refer to it.
+/// So when [`Or`], [`Opt`], [`Range`] or any other combinator backtracks
+/// to an earlier input, the rules see the state as it was at that point,
+/// and the match that [`longest`] picks among several branches keeps
+/// the state of its own branch. Snapshots of abandoned branches are freed
+/// with their inputs. Since a modification may clone the state, use
+/// a cheap to clone type for a large one.
+///
+/// [`input`]: ./struct.State.html#method.input
+/// [`Rule::update`]: ./trait.Rule.html#method.update
+/// [`Rule::check`]: ./trait.Rule.html#method.check
+/// [`lift`]: ./compound/fn.lift.html
+/// [`Stateful`]: ./struct.Stateful.html
+/// [`Or`]: ./compound/struct.Or.html
+/// [`Opt`]: ./compound/struct.Opt.html
+/// [`Range`]: ./compound/struct.Range.html
+/// [`longest`]: ./compound/fn.longest.html
+///
+/// # Examples
+///
+/// ```
+/// # use parsers::{prelude::*, State, compound::{lift, take_while1}};
+/// let name = take_while1(|c: char| c.is_ascii_lowercase());
+///
+/// // Declares a name, then accepts only declared names
+/// let decl = lift(rul("let ") >> name).update(|names: &mut Vec<String>, n: &&str| names.push(n.to_string()));
+/// let usage = lift(name).check(|names: &Vec<String>, n: &&str| names.iter().any(|d| d == n));
+///
+/// let state = State::new(vec![]);
+/// let rule = (decl, lift(';'), usage);
+/// assert!(rule.rule(state.input("let x;x")).is_match());
+/// assert!(rule.rule(state.input("let x;y")).is_expected());
+/// ```
+#[derive(Debug)]
+pub struct State<S> {
+    init: Rc<S>,
+}
+
+impl<S> State<S> {
+    /// Creates the state with the initial value.
+    pub fn new(init: S) -> Self {
+        State {
+            init: Rc::new(init),
+        }
+    }
+
+    /// Wraps the input to parse it with the initial value of the state.
+    pub fn input<I>(&self, input: I) -> Stateful<I, S> {
+        Stateful {
+            input,
+            state: Rc::clone(&self.init),
+        }
+    }
+}
+
+impl<S> Default for State<S>
+    where
+        S: Default,
+{
+    fn default() -> Self { State::new(S::default()) }
+}
+
+/// An input with the [`State`].
+///
+/// Combinators pass the wrapper through, while stateless rules such as chars,
+/// strings and [basic] rules are applied to the inner input with [`lift`].
+///
+/// [`State`]: ./struct.State.html
+/// [basic]: ./basic/index.html
+/// [`lift`]: ./compound/fn.lift.html
+#[derive(Debug)]
+pub struct Stateful<I, S> {
+    input: I,
+    state: Rc<S>,
+}
+
+impl<I, S> Clone for Stateful<I, S>
+    where
+        I: Clone,
+{
+    fn clone(&self) -> Self {
+        Stateful {
+            input: self.input.clone(),
+            state: Rc::clone(&self.state),
+        }
+    }
+}
+
+impl<I, S> Stateful<I, S> {
+    /// Returns the inner input.
+    pub fn input(&self) -> I
+        where
+            I: Clone,
+    { self.input.clone() }
+
+    /// Calls `f` with the state as it is at this input.
+    pub fn with<F, K>(&self, f: F) -> K
+        where
+            F: FnOnce(&S) -> K,
+    { f(&self.state) }
+
+    /// Modifies the state and returns the input that sees the modification.
+    ///
+    /// Other inputs keep seeing their own snapshots of the state.
+    pub fn update<F>(mut self, f: F) -> Self
+        where
+            F: FnOnce(&mut S),
+            S: Clone,
+    {
+        f(Rc::make_mut(&mut self.state));
+        self
+    }
+
+    /// Replaces the inner input keeping the state.
+    pub fn advance(self, input: I) -> Self { Stateful { input, ..self } }
+}
+
+/// An interface of inputs that carry a user state.
+pub trait HasState {
+    /// The type of the state.
+    type State;
+}
+
+impl<I, S> HasState for Stateful<I, S> {
+    type State = S;
+}
+
+impl<I, S> IsEmpty for Stateful<I, S>
+    where
+        I: IsEmpty,
+{
+    fn is_empty(&self) -> bool { self.input.is_empty() }
+}
+
+impl<I, S> Consumed for Stateful<I, S>
+    where
+        I: Consumed,
+{
+    fn rest_len(&self) -> usize { self.input.rest_len() }
+
+    fn consumed(self, rest: Self) -> Self {
+        let input = self.input.clone().consumed(rest.input);
+        self.advance(input)
+    }
+
+    fn suffix(self, len: usize) -> Self {
+        let input = self.input.clone().suffix(len);
+        self.advance(input)
+    }
+}
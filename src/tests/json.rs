@@ -1,15 +1,10 @@
-use std::str::FromStr;
-
 use crate::{
-    Apply,
-    Ruled,
-    rule,
-    SomeOf,
-    common::*,
+    prelude::*,
+    basic::{any, prefixed_int, whites},
 };
 
 #[derive(Debug, Eq, PartialEq)]
-enum Json<'i> {
+pub enum Json<'i> {
     Num(i64),
     Str(&'i str),
     Bool(bool),
@@ -17,88 +12,30 @@ enum Json<'i> {
     Obj(Vec<(&'i str, Json<'i>)>),
 }
 
-#[derive(Debug, Eq, PartialEq)]
-enum JsonError {
-    IncorrectNum(String),
-    Expected(SomeOf<'static>),
-}
-
-impl From<SomeOf<'static>> for JsonError {
-    fn from(err: SomeOf<'static>) -> Self { JsonError::Expected(err) }
-}
-
-fn str(code: &str) -> Ruled<&str, &str, JsonError> {
-    (rule('"') >> (rule("\\\"") | any()).until('"'))
-        .map_err(|e| JsonError::Expected(e))
-        .map(|(s, _)| s)
-        .apply(code)
-}
-
-fn read_num(code: &str) -> Ruled<&str, Json, JsonError> {
-    let minus = rule('-').or_default();
-    let dec = minus & dec() * (1..);
-
-    let bin = rule("0b") >> bin() * (1..);
-    let oct = rule("0o") >> oct() * (1..);
-    let hex = rule("0x") >> hex() * (1..);
-
-    let num =
-          bin.map(|s| i64::from_str_radix(&*s, 2).map_err(|_| JsonError::IncorrectNum(s)))
-        | oct.map(|s| i64::from_str_radix(&*s, 8).map_err(|_| JsonError::IncorrectNum(s)))
-        | hex.map(|s| i64::from_str_radix(&*s, 16).map_err(|_| JsonError::IncorrectNum(s)))
-        | dec.map(|s| i64::from_str(&*s).map_err(|_| JsonError::IncorrectNum(s)));
-
-    num
-        .and_then(|r| r)
-        .map(|n| Json::Num(n))
-        .apply(code)
-}
-
-fn read_str(code: &str) -> Ruled<&str, Json, JsonError> {
-    rule(str)
-        .map(|s| Json::Str(s))
-        .apply(code)
-}
-
-fn read_bool(code: &str) -> Ruled<&str, Json, JsonError> {
-    let t = rule("true").map(|_| true);
-    let f = rule("false").map(|_| false);
-
-    (t | f)
-        .map(|b| Json::Bool(b))
-        .map_err(|e| JsonError::Expected(e))
-        .apply(code)
-}
-
-//noinspection RsBorrowChecker
-fn read_array(code: &str) -> Ruled<&str, Json, JsonError> {
-    let el = rule(read_json);
-    let els = (el << whites() << ',').range(..).cat(el);
-    let array = rule('[') >> els.or_default() << whites() << ']';
-
-    array
-        .map(|els| Json::Array(els))
-        .apply(code)
-}
-
-//noinspection RsBorrowChecker
-fn read_obj(code: &str) -> Ruled<&str, Json, JsonError> {
-    let key = rule(str);
-    let value = rule(read_json);
-    let el = rule((key, rule(whites()) >> ':' >> value));
-    let els = (el << whites() << ',' << whites()).range(..).cat(el);
-    let obj = rule('{') >> whites() >> els.or_default() << whites() << '}';
-
-    obj
-        .map(|els| Json::Obj(els))
-        .apply(code)
+fn list<T>((first, rest): (T, Vec<(&str, &str, T)>)) -> Vec<T> {
+    let mut items = vec![first];
+    items.extend(rest.into_iter().map(|(_, _, item)| item));
+    items
 }
 
-fn read_json(code: &str) -> Ruled<&str, Json, JsonError> {
-    let json = rule(read_num) | read_str | read_bool | read_array | read_obj;
-
-    (rule(whites()) >> json)
-        .apply(code)
+crate::grammar! {
+    mod json;
+
+    value: Json<'i> = ws item => |(_, v)| v;
+    item: Json<'i> = number => Json::Num
+        / string => Json::Str
+        / "true" => |_| Json::Bool(true)
+        / "false" => |_| Json::Bool(false)
+        / '[' items? ws ']' => |(_, items, _, _)| Json::Array(items.unwrap_or_default())
+        / '{' members? ws '}' => |(_, members, _, _)| Json::Obj(members.unwrap_or_default());
+    items: Vec<Json<'i>> = value (ws ',' value)* => list;
+    members: Vec<(&'i str, Json<'i>)> = member (ws ',' member)* => list;
+    member: (&'i str, Json<'i>) = ws string ws ':' value => |(_, k, _, _, v)| (k, v);
+    string: &'i str = '"' chars '"' => |(_, s, _)| s;
+    chars: &'i str = { rul(escape()).or(any() - '"').range::<&'i str, _>(..) };
+    escape: &'i str = "\\\"";
+    number: i64 = { prefixed_int::<i64>() };
+    ws: &'i str = { whites() };
 }
 
 #[test]
@@ -140,6 +77,11 @@ fn json() {
         ])),
     ]);
 
-    let json = read_json(code).result().unwrap();
-    assert_eq!(json, expected);
+    assert_eq!(json::value().rule(code), Match(expected, "\n    "));
+    assert!(json::value().rule("[1,").is_expected());
+
+    assert_eq!(json::value().rule("0x7fffffffffffffff"), Match(Json::Num(i64::MAX), ""));
+    assert_eq!(json::value().rule("-9223372036854775808"), Match(Json::Num(i64::MIN), ""));
+    assert_eq!(json::value().rule("1234567890123456789"), Match(Json::Num(1234567890123456789), ""));
+    assert!(json::value().rule("0x8000000000000000").is_expected());
 }
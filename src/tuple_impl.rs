@@ -1,4 +1,8 @@
-use super::prelude::*;
+use super::{
+    prelude::*,
+    compound::{Longest, Permutation},
+    Consumed,
+};
 
 impl<'r, I, P0> Rule<'r, I> for (P0, )
     where
@@ -165,3 +169,113 @@ impl<'r, I, P0, P1, P2, P3, P4, P5, P6> Rule<'r, I> for (P0, P1, P2, P3, P4, P5,
             )
     }
 }
+
+macro_rules! impl_permutation {
+    ($p0:ident $n0:tt $(, $p:ident $n:tt)+) => {
+        impl<'r, I, $p0, $($p),+> Rule<'r, I> for Permutation<($p0, $($p),+)>
+            where
+                $p0: Rule<'r, I>,
+                $($p: Rule<'r, I, Exp=$p0::Exp>,)+
+                I: Consumed,
+        {
+            type Mat = ($p0::Mat, $($p::Mat),+);
+            type Exp = $p0::Exp;
+
+            fn rule(&'r self, mut input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+                let rules = &self.0;
+                let mut slots = (None, $(None::<$p::Mat>),+);
+
+                // Take members that consume the input, the first matched wins
+                'progress: loop {
+                    if slots.$n0.is_none() {
+                        if let Match(m, i) = rules.$n0.rule(input.clone()) {
+                            if i.rest_len() < input.rest_len() {
+                                slots.$n0 = Some(m);
+                                input = i;
+                                continue 'progress;
+                            }
+                        }
+                    }
+
+                    $(
+                        if slots.$n.is_none() {
+                            if let Match(m, i) = rules.$n.rule(input.clone()) {
+                                if i.rest_len() < input.rest_len() {
+                                    slots.$n = Some(m);
+                                    input = i;
+                                    continue 'progress;
+                                }
+                            }
+                        }
+                    )+
+
+                    break;
+                }
+
+                // The rest members must match the empty input
+                if slots.$n0.is_none() {
+                    match rules.$n0.rule(input) {
+                        Match(m, i) => { slots.$n0 = Some(m); input = i; }
+                        Expected(e) => return Expected(e),
+                    }
+                }
+
+                $(
+                    if slots.$n.is_none() {
+                        match rules.$n.rule(input) {
+                            Match(m, i) => { slots.$n = Some(m); input = i; }
+                            Expected(e) => return Expected(e),
+                        }
+                    }
+                )+
+
+                let mats = (slots.$n0.unwrap(), $(slots.$n.unwrap()),+);
+                Match(mats, input)
+            }
+        }
+    };
+}
+
+impl_permutation!(P0 0, P1 1);
+impl_permutation!(P0 0, P1 1, P2 2);
+impl_permutation!(P0 0, P1 1, P2 2, P3 3);
+impl_permutation!(P0 0, P1 1, P2 2, P3 3, P4 4);
+impl_permutation!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5);
+impl_permutation!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6);
+
+macro_rules! impl_longest {
+    ($p0:ident $n0:tt $(, $p:ident $n:tt)+) => {
+        impl<'r, I, $p0, $($p),+> Rule<'r, I> for Longest<($p0, $($p),+)>
+            where
+                $p0: Rule<'r, I>,
+                $($p: Rule<'r, I, Mat=$p0::Mat, Exp=$p0::Exp>,)+
+                I: Consumed,
+        {
+            type Mat = $p0::Mat;
+            type Exp = $p0::Exp;
+
+            fn rule(&'r self, input: I) -> Ruled<I, Self::Mat, Self::Exp> {
+                let rules = &self.0;
+                let mut best = rules.$n0.rule(input.clone());
+
+                $(
+                    match (rules.$n.rule(input.clone()), &best) {
+                        (Match(m, i), Match(_, b)) if i.rest_len() < b.rest_len() => best = Match(m, i),
+                        (Match(m, i), Expected(_)) => best = Match(m, i),
+                        (Expected(e), Expected(_)) => best = Expected(e),
+                        _ => {}
+                    }
+                )+
+
+                best
+            }
+        }
+    };
+}
+
+impl_longest!(P0 0, P1 1);
+impl_longest!(P0 0, P1 1, P2 2);
+impl_longest!(P0 0, P1 1, P2 2, P3 3);
+impl_longest!(P0 0, P1 1, P2 2, P3 3, P4 4);
+impl_longest!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5);
+impl_longest!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6);